@@ -0,0 +1,331 @@
+//! With the `subset` feature enabled the bundled text fonts are trimmed to the
+//! unicode ranges listed in the `DEAR_EGUI_SUBSET` environment variable before
+//! they are embedded.
+//!
+//! `DEAR_EGUI_SUBSET` is a comma separated list of named ranges
+//! (`latin`, `latin-1`, `latin-ext`, `greek`, `cyrillic`, `punctuation`, `currency`, `arrows`)
+//! or explicit ranges like `U+2190-21FF`. It defaults to `latin,latin-1`.
+//!
+//! The build fails if a subset font does not parse or lost a glyph of the ranges.
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    #[cfg(feature = "subset")]
+    subset::run();
+}
+
+#[cfg(feature = "subset")]
+mod subset {
+    use std::{
+        collections::{BTreeMap, BTreeSet},
+        env, fs,
+        ops::RangeInclusive,
+        path::Path,
+    };
+
+    /// The fonts from the `font` folder that are embedded as text fonts.
+    const FONTS: [&str; 2] = ["ProggyClean.ttf", "OpenSans-Regular.ttf"];
+
+    const DEFAULT_RANGES: &str = "latin,latin-1";
+
+    pub fn run() {
+        println!("cargo:rerun-if-env-changed=DEAR_EGUI_SUBSET");
+        let spec = env::var("DEAR_EGUI_SUBSET").unwrap_or_else(|_| DEFAULT_RANGES.to_owned());
+        let ranges = parse_ranges(&spec).unwrap_or_else(|err| panic!("DEAR_EGUI_SUBSET: {err}"));
+
+        let out_dir = env::var("OUT_DIR").unwrap();
+        for name in FONTS {
+            let path = Path::new("font").join(name);
+            println!("cargo:rerun-if-changed={}", path.display());
+            let data = fs::read(&path).unwrap();
+            let subset = subset_font(&data, &ranges)
+                .unwrap_or_else(|err| panic!("Failed to subset {name}: {err}"));
+            check_subset(&data, &subset, &ranges)
+                .unwrap_or_else(|err| panic!("The subset of {name} is broken: {err}"));
+            fs::write(Path::new(&out_dir).join(name), subset).unwrap();
+        }
+    }
+
+    fn parse_ranges(spec: &str) -> Result<Vec<RangeInclusive<u32>>, String> {
+        let mut ranges = Vec::new();
+        for part in spec
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+        {
+            let range = match part.to_lowercase().as_str() {
+                "latin" => 0x20..=0x7E,
+                "latin-1" => 0xA0..=0xFF,
+                "latin-ext" => 0x100..=0x24F,
+                "greek" => 0x370..=0x3FF,
+                "cyrillic" => 0x400..=0x4FF,
+                "punctuation" => 0x2000..=0x206F,
+                "currency" => 0x20A0..=0x20CF,
+                "arrows" => 0x2190..=0x21FF,
+                range => {
+                    let range = range.trim_start_matches("u+");
+                    let (start, end) = range.split_once('-').unwrap_or((range, range));
+                    let parse = |s: &str| {
+                        u32::from_str_radix(s.trim_start_matches("u+"), 16)
+                            .map_err(|_| format!("Invalid range `{part}`"))
+                    };
+                    parse(start)?..=parse(end)?
+                }
+            };
+            ranges.push(range);
+        }
+        Ok(ranges)
+    }
+
+    /// Remove the outlines of all glyphs that are not reachable from a character in
+    /// one of the ranges and only map those characters.
+    ///
+    /// Glyph ids are kept as they are so that all other tables stay valid.
+    fn subset_font(data: &[u8], ranges: &[RangeInclusive<u32>]) -> Result<Vec<u8>, String> {
+        let face = ttf_parser::Face::parse(data, 0).map_err(|err| err.to_string())?;
+        let mut tables = read_tables(data)?;
+
+        let mut mapping = BTreeMap::new();
+        for c in ranges.iter().cloned().flatten().filter_map(char::from_u32) {
+            if let Some(glyph) = face.glyph_index(c) {
+                mapping.insert(c as u32, glyph.0);
+            }
+        }
+
+        let num_glyphs = face.number_of_glyphs() as usize;
+        let head = tables.get(b"head").ok_or("Missing head table")?;
+        let long_loca = read_u16(head, 50)? == 1;
+        let loca = tables.get(b"loca").ok_or("Missing loca table")?;
+        let glyf = tables.get(b"glyf").ok_or("Missing glyf table")?;
+        let offsets = (0..=num_glyphs)
+            .map(|index| match long_loca {
+                true => read_u32(loca, index * 4).map(|offset| offset as usize),
+                false => read_u16(loca, index * 2).map(|offset| offset as usize * 2),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let outline = |glyph: usize| glyf.get(offsets[glyph]..offsets[glyph + 1]).unwrap_or(&[]);
+
+        // Keep the notdef glyph and every component of a kept composite glyph.
+        let mut keep = BTreeSet::from([0]);
+        let mut pending: Vec<usize> = mapping.values().map(|&glyph| glyph as usize).collect();
+        while let Some(glyph) = pending.pop() {
+            if glyph < num_glyphs && keep.insert(glyph) {
+                pending.extend(components(outline(glyph))?);
+            }
+        }
+
+        let mut new_glyf = Vec::new();
+        let mut new_loca = Vec::new();
+        for glyph in 0..num_glyphs {
+            new_loca.extend((new_glyf.len() as u32).to_be_bytes());
+            if keep.contains(&glyph) {
+                new_glyf.extend(outline(glyph));
+                new_glyf.resize(new_glyf.len().next_multiple_of(4), 0);
+            }
+        }
+        new_loca.extend((new_glyf.len() as u32).to_be_bytes());
+
+        let mut head = head.clone();
+        head[50..52].copy_from_slice(&1u16.to_be_bytes());
+        tables.insert(*b"head", head);
+        tables.insert(*b"loca", new_loca);
+        tables.insert(*b"glyf", new_glyf);
+        tables.insert(*b"cmap", write_cmap(&mapping));
+        // The signature does not match the modified font anymore.
+        tables.remove(b"DSIG");
+
+        Ok(write_font(data, tables))
+    }
+
+    /// Check that the subset parses, maps exactly the characters of the original font that
+    /// are in the ranges and keeps their outlines.
+    fn check_subset(
+        original: &[u8],
+        subset: &[u8],
+        ranges: &[RangeInclusive<u32>],
+    ) -> Result<(), String> {
+        let original = ttf_parser::Face::parse(original, 0).map_err(|err| err.to_string())?;
+        let subset = ttf_parser::Face::parse(subset, 0).map_err(|err| err.to_string())?;
+        let mut codepoints = BTreeSet::new();
+        for table in original
+            .tables()
+            .cmap
+            .iter()
+            .flat_map(|cmap| cmap.subtables)
+        {
+            if table.is_unicode() {
+                table.codepoints(|c| {
+                    codepoints.insert(c);
+                });
+            }
+        }
+        for c in codepoints.into_iter().filter_map(char::from_u32) {
+            let in_ranges = ranges.iter().any(|range| range.contains(&(c as u32)));
+            let glyph = subset.glyph_index(c);
+            if !in_ranges {
+                if glyph.is_some() {
+                    return Err(format!("U+{:04X} is mapped but not requested", c as u32));
+                }
+                continue;
+            }
+            let Some(original_glyph) = original.glyph_index(c) else {
+                continue;
+            };
+            if glyph != Some(original_glyph)
+                || subset.glyph_bounding_box(original_glyph)
+                    != original.glyph_bounding_box(original_glyph)
+            {
+                return Err(format!("U+{:04X} lost its glyph", c as u32));
+            }
+        }
+        Ok(())
+    }
+
+    /// The glyph ids of the components of a composite glyph.
+    fn components(outline: &[u8]) -> Result<Vec<usize>, String> {
+        const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+        const WE_HAVE_A_SCALE: u16 = 0x0008;
+        const MORE_COMPONENTS: u16 = 0x0020;
+        const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+        const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+
+        let mut components = Vec::new();
+        if outline.is_empty() || (read_u16(outline, 0)? as i16) >= 0 {
+            return Ok(components);
+        }
+        let mut offset = 10;
+        loop {
+            let flags = read_u16(outline, offset)?;
+            components.push(read_u16(outline, offset + 2)? as usize);
+            offset += 4;
+            offset += if flags & ARG_1_AND_2_ARE_WORDS != 0 {
+                4
+            } else {
+                2
+            };
+            if flags & WE_HAVE_A_SCALE != 0 {
+                offset += 2;
+            } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+                offset += 4;
+            } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+                offset += 8;
+            }
+            if flags & MORE_COMPONENTS == 0 {
+                return Ok(components);
+            }
+        }
+    }
+
+    /// A cmap table with a single format 12 subtable for the unicode platforms.
+    fn write_cmap(mapping: &BTreeMap<u32, u16>) -> Vec<u8> {
+        // Merge consecutive characters that map to consecutive glyphs into groups.
+        let mut groups: Vec<(u32, u32, u32)> = Vec::new();
+        for (&c, &glyph) in mapping {
+            match groups.last_mut() {
+                Some((start, end, start_glyph))
+                    if *end + 1 == c && *start_glyph + (c - *start) == glyph as u32 =>
+                {
+                    *end = c
+                }
+                _ => groups.push((c, c, glyph as u32)),
+            }
+        }
+
+        let mut cmap = Vec::new();
+        cmap.extend(0u16.to_be_bytes()); // version
+        cmap.extend(2u16.to_be_bytes()); // number of encoding records
+        for (platform, encoding) in [(0u16, 4u16), (3, 10)] {
+            cmap.extend(platform.to_be_bytes());
+            cmap.extend(encoding.to_be_bytes());
+            cmap.extend(20u32.to_be_bytes()); // offset of the subtable
+        }
+        cmap.extend(12u16.to_be_bytes()); // format
+        cmap.extend(0u16.to_be_bytes()); // reserved
+        cmap.extend((16 + 12 * groups.len() as u32).to_be_bytes()); // length
+        cmap.extend(0u32.to_be_bytes()); // language
+        cmap.extend((groups.len() as u32).to_be_bytes());
+        for (start, end, start_glyph) in groups {
+            cmap.extend(start.to_be_bytes());
+            cmap.extend(end.to_be_bytes());
+            cmap.extend(start_glyph.to_be_bytes());
+        }
+        cmap
+    }
+
+    fn read_tables(data: &[u8]) -> Result<BTreeMap<[u8; 4], Vec<u8>>, String> {
+        let num_tables = read_u16(data, 4)? as usize;
+        let mut tables = BTreeMap::new();
+        for index in 0..num_tables {
+            let record = 12 + 16 * index;
+            let tag = data
+                .get(record..record + 4)
+                .ok_or("Truncated table record")?
+                .try_into()
+                .unwrap();
+            let offset = read_u32(data, record + 8)? as usize;
+            let length = read_u32(data, record + 12)? as usize;
+            let table = data.get(offset..offset + length).ok_or("Truncated table")?;
+            tables.insert(tag, table.to_vec());
+        }
+        Ok(tables)
+    }
+
+    fn write_font(original: &[u8], mut tables: BTreeMap<[u8; 4], Vec<u8>>) -> Vec<u8> {
+        // The checksum adjustment is calculated once the whole font is written.
+        if let Some(head) = tables.get_mut(b"head") {
+            head[8..12].fill(0);
+        }
+
+        let num_tables = tables.len() as u16;
+        let entry_selector = 15 - num_tables.leading_zeros() as u16;
+        let search_range = (1u16 << entry_selector) * 16;
+
+        let mut font = Vec::new();
+        font.extend(&original[0..4]); // sfnt version
+        font.extend(num_tables.to_be_bytes());
+        font.extend(search_range.to_be_bytes());
+        font.extend(entry_selector.to_be_bytes());
+        font.extend((num_tables * 16 - search_range).to_be_bytes());
+
+        let mut offset = 12 + 16 * tables.len();
+        let mut head_offset = 0;
+        for (tag, table) in &tables {
+            if tag == b"head" {
+                head_offset = offset;
+            }
+            font.extend(tag);
+            font.extend(checksum(table).to_be_bytes());
+            font.extend((offset as u32).to_be_bytes());
+            font.extend((table.len() as u32).to_be_bytes());
+            offset += table.len().next_multiple_of(4);
+        }
+        for table in tables.values() {
+            font.extend(table);
+            font.resize(font.len().next_multiple_of(4), 0);
+        }
+
+        let adjustment = 0xB1B0_AFBAu32.wrapping_sub(checksum(&font));
+        font[head_offset + 8..head_offset + 12].copy_from_slice(&adjustment.to_be_bytes());
+        font
+    }
+
+    fn checksum(data: &[u8]) -> u32 {
+        data.chunks(4).fold(0u32, |sum, chunk| {
+            let mut word = [0; 4];
+            word[..chunk.len()].copy_from_slice(chunk);
+            sum.wrapping_add(u32::from_be_bytes(word))
+        })
+    }
+
+    fn read_u16(data: &[u8], offset: usize) -> Result<u16, String> {
+        data.get(offset..offset + 2)
+            .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+            .ok_or_else(|| "Unexpected end of data".to_owned())
+    }
+
+    fn read_u32(data: &[u8], offset: usize) -> Result<u32, String> {
+        data.get(offset..offset + 4)
+            .map(|bytes| u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .ok_or_else(|| "Unexpected end of data".to_owned())
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::{ops::RangeInclusive, time::Instant};
 
+use dear_egui::typography::TypographyExt;
 use eframe::egui;
 use egui::{Layout, ViewportBuilder};
 
@@ -24,6 +25,12 @@ fn main() -> Result<(), eframe::Error> {
             Box::new(MyApp {
                 theme: dear_egui::Theme::Imgui,
                 font: dear_egui::Font::OpenSans,
+                applied: (dear_egui::Theme::Imgui, dear_egui::Font::OpenSans),
+                editor: dear_egui::StyleEditor::new(
+                    dear_egui::Theme::Imgui,
+                    dear_egui::Font::OpenSans,
+                ),
+                show_editor: false,
                 some_bool: false,
                 counter: 0,
                 last_frame: Instant::now(),
@@ -31,8 +38,6 @@ fn main() -> Result<(), eframe::Error> {
                 r: 0,
                 g: 0,
                 b: 0,
-                custom_hue: 0.5,
-                custom_brightness: 0.5,
             })
         }),
     )
@@ -40,9 +45,10 @@ fn main() -> Result<(), eframe::Error> {
 
 struct MyApp {
     theme: dear_egui::Theme,
-    custom_hue: f32,
-    custom_brightness: f32,
     font: dear_egui::Font,
+    applied: (dear_egui::Theme, dear_egui::Font),
+    editor: dear_egui::StyleEditor,
+    show_editor: bool,
     some_bool: bool,
     counter: i32,
     last_frame: Instant,
@@ -54,7 +60,12 @@ struct MyApp {
 
 impl eframe::App for MyApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        dear_egui::set_theme(ctx, self.theme, self.font);
+        // Only apply the theme when it changes so that the style editor can edit it.
+        if (self.theme, self.font) != self.applied {
+            dear_egui::set_theme(ctx, self.theme, self.font);
+            self.editor = dear_egui::StyleEditor::new(self.theme, self.font);
+            self.applied = (self.theme, self.font);
+        }
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Dear-Egui");
             ui.separator();
@@ -71,67 +82,7 @@ impl eframe::App for MyApp {
                     }
                 });
             });
-            if let dear_egui::Theme::Custom { hue, brightness } = &mut self.theme {
-                ui.horizontal(|ui| {
-                    ui.label("Hue:");
-                    ui.add(egui::DragValue::new(hue).clamp_range(RangeInclusive::new(0.0, 360.0)));
-                    ui.label("Brightness:");
-                    ui.add(
-                        egui::DragValue::new(brightness)
-                            .clamp_range(RangeInclusive::new(0.0, 2.0))
-                            .speed(0.01),
-                    );
-                });
-            }
-            ui.horizontal(|ui| {
-                let mut tab = |theme: dear_egui::Theme, label: &str| {
-                    let text_styles = ui.style().text_styles.clone();
-                    ui.set_style(theme.get_style());
-                    ui.style_mut().text_styles = text_styles;
-                    ui.visuals_mut().widgets.inactive.rounding = egui::Rounding {
-                        se: 0.0,
-                        sw: 0.0,
-                        ..ui.visuals().widgets.inactive.rounding
-                    };
-                    ui.visuals_mut().widgets.hovered.rounding = egui::Rounding {
-                        se: 0.0,
-                        sw: 0.0,
-                        ..ui.visuals().widgets.hovered.rounding
-                    };
-                    ui.visuals_mut().widgets.active.rounding = egui::Rounding {
-                        se: 0.0,
-                        sw: 0.0,
-                        ..ui.visuals().widgets.active.rounding
-                    };
-                    ui.selectable_value(&mut self.theme, theme, label);
-                };
-                tab(dear_egui::Theme::Imgui, "Imgui");
-                tab(dear_egui::Theme::Cadmium, "Cadmium");
-                tab(dear_egui::Theme::Acid, "Acid");
-                tab(dear_egui::Theme::Forest, "Forest");
-                tab(dear_egui::Theme::Sky, "Sky");
-                tab(dear_egui::Theme::Iris, "Iris");
-                tab(dear_egui::Theme::Violet, "Violet");
-                tab(dear_egui::Theme::Raspberry, "Raspberry");
-                tab(
-                    dear_egui::Theme::Custom {
-                        hue: self.custom_hue,
-                        brightness: self.custom_brightness,
-                    },
-                    "Custom",
-                );
-            });
-            //ui.allocate_space(egui::vec2(0.0, -8.0));
-            //ui.separator();
-            ui.painter().rect_filled(
-                egui::Rect::from_min_size(
-                    ui.cursor().min + egui::vec2(0.0, -ui.spacing().item_spacing.y),
-                    egui::vec2(ui.available_width(), 2.0),
-                ),
-                0.0,
-                ui.visuals().selection.bg_fill,
-            );
-            //ui.allocate_space(egui::vec2(0.0, 0.0));
+            ui.add(dear_egui::ThemePicker::new(&mut self.theme));
 
             egui::Frame::none()
                 .inner_margin(egui::Margin::same(3.0))
@@ -139,6 +90,7 @@ impl eframe::App for MyApp {
                     ui.label("This is some useful text.");
                     ui.checkbox(&mut self.some_bool, "Demo Window");
                     ui.checkbox(&mut self.some_bool, "Another Window");
+                    ui.checkbox(&mut self.show_editor, "Style Editor");
 
                     ui.add(
                         egui::Slider::new(&mut self.float, RangeInclusive::new(0.0, 1.0))
@@ -196,11 +148,17 @@ impl eframe::App for MyApp {
                         ui.strong("Strong text");
                     });
                     ui.heading("Heading text");
+                    ui.horizontal(|ui| {
+                        ui.subheading("Subheading text");
+                        ui.add_space(10.0);
+                        ui.caption("Caption text");
+                    });
                     ui.monospace("012356789  ! Monospace text.");
                     ui.monospace("Hello world! This works good.");
                     _ = ui.button("Button text");
                 });
         });
+        self.editor.window(ctx, &mut self.show_editor);
         ctx.request_repaint();
     }
 }
@@ -0,0 +1,107 @@
+use egui::Context;
+
+use crate::{FontSetup, Theme};
+
+/// A pair of a dark and a light theme that follows the dark mode of the system.
+///
+/// eframe reports a change of the system theme by replacing the visuals of the
+/// context with egui's dark or light visuals. [`AutoTheme::update`] picks up that
+/// change and applies the matching theme. The theme is only applied when the mode changes.
+/// ```no_run
+/// # use dear_egui::{AutoTheme, Font, Theme};
+/// # fn f(ctx: &egui::Context) {
+/// let mut auto = AutoTheme::new(Theme::Imgui, Theme::SolarizedLight).font(Font::OpenSans);
+/// // In the update loop:
+/// auto.update(ctx);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AutoTheme {
+    pub dark: Theme,
+    pub light: Theme,
+    font: Option<FontSetup>,
+    dark_mode: Option<bool>,
+}
+
+impl AutoTheme {
+    pub fn new(dark: Theme, light: Theme) -> Self {
+        Self {
+            dark,
+            light,
+            font: None,
+            dark_mode: None,
+        }
+    }
+
+    /// Use `font` for both themes instead of the font of each theme, see [`Theme::font_setup`].
+    pub fn font(mut self, font: impl Into<FontSetup>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// The theme for the current mode, once one has been applied.
+    pub fn current(&self) -> Option<Theme> {
+        self.dark_mode
+            .map(|dark_mode| if dark_mode { self.dark } else { self.light })
+    }
+
+    /// Apply the theme that matches the dark mode reported by `ctx` if it changed.
+    pub fn update(&mut self, ctx: &Context) {
+        let dark_mode = ctx.style().visuals.dark_mode;
+        self.set_dark_mode(ctx, dark_mode);
+    }
+
+    /// Apply the dark or light theme if the mode changed,
+    /// for example from `eframe::IntegrationInfo::system_theme`.
+    pub fn set_dark_mode(&mut self, ctx: &Context, dark_mode: bool) {
+        if self.dark_mode == Some(dark_mode) {
+            return;
+        }
+        self.dark_mode = Some(dark_mode);
+        let theme = if dark_mode { self.dark } else { self.light };
+        let font = self.font.clone().unwrap_or_else(|| theme.font_setup());
+        let mut style = theme.get_style_with_font(&font);
+        // Keep the reported mode so that the next update does not switch back.
+        style.visuals.dark_mode = dark_mode;
+        ctx.set_fonts(font.font_definitions());
+        ctx.set_style(style);
+    }
+
+    /// Apply the theme for the current mode again, for example after changing the themes.
+    pub fn reapply(&mut self, ctx: &Context) {
+        if let Some(dark_mode) = self.dark_mode.take() {
+            self.set_dark_mode(ctx, dark_mode);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_documented_themes_have_the_right_mode() {
+        assert!(Theme::Imgui.get_base_style().visuals.dark_mode);
+        for light in [
+            Theme::SolarizedLight,
+            Theme::GruvboxLight,
+            Theme::CatppuccinLatte,
+        ] {
+            assert!(!light.get_base_style().visuals.dark_mode, "{light}");
+        }
+    }
+
+    #[test]
+    fn the_mode_of_the_context_is_followed() {
+        let ctx = Context::default();
+        let mut auto = AutoTheme::new(Theme::Imgui, Theme::SolarizedLight);
+        auto.set_dark_mode(&ctx, false);
+        assert_eq!(auto.current(), Some(Theme::SolarizedLight));
+        assert!(!ctx.style().visuals.dark_mode);
+        auto.update(&ctx);
+        assert_eq!(auto.current(), Some(Theme::SolarizedLight));
+        auto.set_dark_mode(&ctx, true);
+        assert_eq!(auto.current(), Some(Theme::Imgui));
+        assert!(ctx.style().visuals.dark_mode);
+    }
+}
@@ -0,0 +1,238 @@
+//! [base16](https://github.com/tinted-theming/home) and base24 color schemes.
+//!
+//! Both the original YAML format with `scheme:` and `base00:` at the top level and
+//! the newer format with a `palette:` section are read. The colors are mapped onto
+//! the palette roles following the base16 styling guidelines:
+//!
+//! | Color | Role |
+//! | --- | --- |
+//! | `base00` | window background |
+//! | `base01`, `base02`, `base03` | frames in their normal, hovered and active state |
+//! | `base02` | borders |
+//! | `base05` | text |
+//! | `base06` | text of hovered and active widgets |
+//! | `base08` | errors |
+//! | `base09` | warnings |
+//! | `base0D` | buttons, selection and hyperlinks |
+//!
+//! A base24 scheme also uses `base16` for hyperlinks, and a dark one `base10` as the fill
+//! of text edits.
+//! ```no_run
+//! # use dear_egui::{Base16Scheme, Font};
+//! # fn f(ctx: &egui::Context) -> Result<(), Box<dyn std::error::Error>> {
+//! let scheme: Base16Scheme = std::fs::read_to_string("gruvbox-dark-hard.yaml")?.parse()?;
+//! let theme = dear_egui::registry::register_theme(scheme);
+//! dear_egui::set_theme(ctx, theme, Font::OpenSans);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::str::FromStr;
+
+use egui::{Color32, Rgba, Style};
+
+use crate::{
+    fields::parse_color,
+    registry::ThemeProvider,
+    schemes::{mix, Scheme},
+    theme_file::ParseError,
+    Palette,
+};
+
+/// A base16 or base24 color scheme.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Base16Scheme {
+    pub name: String,
+    pub author: String,
+    /// The colors `base00` to `base0F`.
+    pub base: [Color32; 16],
+    /// The colors `base10` to `base17` of a base24 scheme.
+    pub base24: Option<[Color32; 8]>,
+}
+
+impl Base16Scheme {
+    /// Whether the background is darker than the text.
+    pub fn is_dark(&self) -> bool {
+        Rgba::from(self.base[0x0]).intensity() < Rgba::from(self.base[0x5]).intensity()
+    }
+
+    /// Map the colors onto the palette roles.
+    pub fn to_palette(&self) -> Palette {
+        let base = &self.base;
+        let extreme_bg = match self.base24 {
+            Some(base24) if self.is_dark() => base24[0x0],
+            _ if self.is_dark() => mix(base[0x0], Color32::BLACK, 0.3),
+            _ => mix(base[0x0], Color32::WHITE, 0.6),
+        };
+        Scheme {
+            bg: base[0x0],
+            extreme_bg,
+            code_bg: base[0x1],
+            surfaces: [base[0x1], base[0x2], base[0x3]],
+            border: base[0x2],
+            text: base[0x5],
+            text_active: base[0x6],
+            accent: base[0xD],
+            hyperlink: self.base24.map_or(base[0xD], |base24| base24[0x6]),
+            warn: base[0x9],
+            error: base[0x8],
+        }
+        .palette()
+    }
+}
+
+impl ThemeProvider for Base16Scheme {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_base_style(&self) -> Style {
+        self.to_palette().get_style()
+    }
+
+    fn palette(&self) -> Option<Palette> {
+        Some(self.to_palette())
+    }
+}
+
+/// Parses a scheme in YAML. Keys other than the name, author and colors are ignored,
+/// as are lines that are not `key: value`, like the text of a `description: >` block.
+impl FromStr for Base16Scheme {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut name = String::new();
+        let mut author = String::new();
+        let mut colors: [Option<Color32>; 24] = [None; 24];
+        for (index, line) in s.lines().enumerate() {
+            let error = |message: String| ParseError {
+                line: index + 1,
+                message,
+            };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line == "---" {
+                continue;
+            }
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = unquote(value.trim());
+            match key.trim() {
+                "scheme" | "name" => name = value.to_owned(),
+                "author" => author = value.to_owned(),
+                key => {
+                    let Some(slot) = key
+                        .strip_prefix("base")
+                        .filter(|digits| digits.len() == 2)
+                        .and_then(|digits| usize::from_str_radix(digits, 16).ok())
+                        .filter(|&slot| slot < colors.len())
+                    else {
+                        continue;
+                    };
+                    colors[slot] = Some(parse_color(value).map_err(error)?);
+                }
+            }
+        }
+
+        let missing = |slot: usize| ParseError {
+            line: s.lines().count(),
+            message: format!("Missing `base{slot:02X}`"),
+        };
+        let mut base = [Color32::BLACK; 16];
+        for (slot, color) in base.iter_mut().enumerate() {
+            *color = colors[slot].ok_or_else(|| missing(slot))?;
+        }
+        let base24 = if colors[16..].iter().any(Option::is_some) {
+            let mut base24 = [Color32::BLACK; 8];
+            for (slot, color) in base24.iter_mut().enumerate() {
+                *color = colors[16 + slot].ok_or_else(|| missing(16 + slot))?;
+            }
+            Some(base24)
+        } else {
+            None
+        };
+        Ok(Self {
+            name,
+            author,
+            base,
+            base24,
+        })
+    }
+}
+
+/// Remove the quotes around a value, or a trailing comment from an unquoted value.
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(rest) = value.strip_prefix(quote) {
+            return rest.split(quote).next().unwrap_or(rest);
+        }
+    }
+    value.split(" #").next().unwrap_or(value).trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLORS: &str = "
+base00: \"282828\"
+base01: \"3c3836\"
+base02: \"504945\"
+base03: \"665c54\"
+base04: \"bdae93\"
+base05: \"d5c4a1\"
+base06: \"ebdbb2\"
+base07: \"fbf1c7\"
+base08: \"fb4934\"
+base09: \"fe8019\"
+base0A: \"fabd2f\"
+base0B: \"b8bb26\"
+base0C: \"8ec07c\"
+base0D: \"83a598\"
+base0E: \"d3869b\"
+base0F: \"d65d0e\"
+";
+
+    #[test]
+    fn original_format() {
+        let text = format!("scheme: \"Gruvbox dark\"\nauthor: 'Dawid Kurek' # comment\n{COLORS}");
+        let scheme: Base16Scheme = text.parse().unwrap();
+        assert_eq!(scheme.name, "Gruvbox dark");
+        assert_eq!(scheme.author, "Dawid Kurek");
+        assert_eq!(scheme.base[0x0], Color32::from_rgb(0x28, 0x28, 0x28));
+        assert_eq!(scheme.base[0xD], Color32::from_rgb(0x83, 0xa5, 0x98));
+        assert_eq!(scheme.base24, None);
+        assert!(scheme.is_dark());
+    }
+
+    #[test]
+    fn palette_format_with_block_scalars() {
+        let colors = COLORS.replace("\nbase", "\n  base");
+        let text = format!(
+            "system: \"base24\"\nname: \"Gruvbox\"\ndescription: >\n  A retro groove\n  color scheme\nvariant: dark\npalette:{colors}  base10: \"1d2021\"\n  base11: 000000\n  base12: fb4934\n  base13: fabd2f\n  base14: b8bb26\n  base15: 8ec07c\n  base16: 83a598\n  base17: d3869b\n"
+        );
+        let scheme: Base16Scheme = text.parse().unwrap();
+        assert_eq!(scheme.name, "Gruvbox");
+        assert_eq!(scheme.base[0xF], Color32::from_rgb(0xd6, 0x5d, 0x0e));
+        let base24 = scheme.base24.unwrap();
+        assert_eq!(base24[0x0], Color32::from_rgb(0x1d, 0x20, 0x21));
+        assert_eq!(scheme.to_palette().extreme_bg, base24[0x0]);
+    }
+
+    #[test]
+    fn malformed_schemes_are_rejected() {
+        let missing = COLORS.replace("base0C: \"8ec07c\"\n", "");
+        let error = missing.parse::<Base16Scheme>().unwrap_err();
+        assert_eq!(error.message, "Missing `base0C`");
+
+        let incomplete_base24 = format!("{COLORS}base10: 1d2021\n");
+        let error = incomplete_base24.parse::<Base16Scheme>().unwrap_err();
+        assert_eq!(error.message, "Missing `base11`");
+
+        let invalid = COLORS.replace("\"fb4934\"", "\"fb49g4\"");
+        let error = invalid.parse::<Base16Scheme>().unwrap_err();
+        assert_eq!(error.line, 10);
+
+        assert!("".parse::<Base16Scheme>().is_err());
+    }
+}
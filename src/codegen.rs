@@ -0,0 +1,369 @@
+//! Render a [`Style`] as Rust source code.
+//!
+//! The generated code has the same layout as [`crate::imgui::get_style`] so that
+//! a theme tweaked at runtime can be frozen into a module of its own.
+//! Every field is written out, so the result compiles without the crate and
+//! only needs a pass of `rustfmt` to match the formatting of the crate.
+//! ```no_run
+//! # fn f(ctx: &egui::Context) -> std::io::Result<()> {
+//! let source = dear_egui::codegen::style_to_rust(&ctx.style());
+//! std::fs::write("src/my_theme.rs", source)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Write;
+
+use egui::{
+    epaint::Shadow,
+    style::{HandleShape, NumericColorSpace, WidgetVisuals},
+    Color32, FontFamily, FontId, Margin, Rounding, Stroke, Style, TextStyle, Vec2,
+};
+
+/// Render `style` as a module with a `pub fn get_style() -> Style` function.
+pub fn style_to_rust(style: &Style) -> String {
+    let mut w = Writer::default();
+    w.line("use egui::{");
+    w.line("    epaint::Shadow,");
+    w.line("    style::{Interaction, ScrollStyle, Selection, Spacing, WidgetVisuals, Widgets},");
+    w.line("    Color32, FontFamily, FontId, Margin, Rounding, Stroke, Style, TextStyle, Vec2, Visuals,");
+    w.line("};");
+    w.line("");
+    w.open("pub fn get_style() -> Style {");
+    w.open("Style {");
+
+    w.line(&format!(
+        "override_text_style: {},",
+        option(&style.override_text_style, text_style)
+    ));
+    w.line(&format!(
+        "override_font_id: {},",
+        option(&style.override_font_id, font_id)
+    ));
+    w.open("text_styles: [");
+    for (name, font) in &style.text_styles {
+        w.line(&format!("({}, {}),", text_style(name), font_id(font)));
+    }
+    w.close("].into(),");
+    w.line(&format!(
+        "drag_value_text_style: {},",
+        text_style(&style.drag_value_text_style)
+    ));
+    w.line(&format!("wrap: {:?},", style.wrap));
+
+    let spacing = &style.spacing;
+    w.open("spacing: Spacing {");
+    w.field("item_spacing", vec2(spacing.item_spacing));
+    w.margin("window_margin", &spacing.window_margin);
+    w.field("button_padding", vec2(spacing.button_padding));
+    w.margin("menu_margin", &spacing.menu_margin);
+    w.field("indent", float(spacing.indent));
+    w.field("interact_size", vec2(spacing.interact_size));
+    w.field("slider_width", float(spacing.slider_width));
+    w.field("combo_width", float(spacing.combo_width));
+    w.field("text_edit_width", float(spacing.text_edit_width));
+    w.field("icon_width", float(spacing.icon_width));
+    w.field("icon_width_inner", float(spacing.icon_width_inner));
+    w.field("icon_spacing", float(spacing.icon_spacing));
+    w.field("tooltip_width", float(spacing.tooltip_width));
+    w.field(
+        "indent_ends_with_horizontal_line",
+        spacing.indent_ends_with_horizontal_line.to_string(),
+    );
+    w.field("combo_height", float(spacing.combo_height));
+    let scroll = &spacing.scroll;
+    w.open("scroll: ScrollStyle {");
+    w.field("floating", scroll.floating.to_string());
+    w.field("bar_width", float(scroll.bar_width));
+    w.field("handle_min_length", float(scroll.handle_min_length));
+    w.field("bar_inner_margin", float(scroll.bar_inner_margin));
+    w.field("bar_outer_margin", float(scroll.bar_outer_margin));
+    w.field("floating_width", float(scroll.floating_width));
+    w.field(
+        "floating_allocated_width",
+        float(scroll.floating_allocated_width),
+    );
+    w.field("foreground_color", scroll.foreground_color.to_string());
+    w.field(
+        "dormant_background_opacity",
+        float(scroll.dormant_background_opacity),
+    );
+    w.field(
+        "active_background_opacity",
+        float(scroll.active_background_opacity),
+    );
+    w.field(
+        "interact_background_opacity",
+        float(scroll.interact_background_opacity),
+    );
+    w.field(
+        "dormant_handle_opacity",
+        float(scroll.dormant_handle_opacity),
+    );
+    w.field("active_handle_opacity", float(scroll.active_handle_opacity));
+    w.field(
+        "interact_handle_opacity",
+        float(scroll.interact_handle_opacity),
+    );
+    w.close("},");
+    w.field("menu_width", float(spacing.menu_width));
+    w.close("},");
+
+    let interaction = &style.interaction;
+    w.open("interaction: Interaction {");
+    w.field(
+        "resize_grab_radius_side",
+        float(interaction.resize_grab_radius_side),
+    );
+    w.field(
+        "resize_grab_radius_corner",
+        float(interaction.resize_grab_radius_corner),
+    );
+    w.field(
+        "show_tooltips_only_when_still",
+        interaction.show_tooltips_only_when_still.to_string(),
+    );
+    w.field("tooltip_delay", float(interaction.tooltip_delay));
+    w.field(
+        "selectable_labels",
+        interaction.selectable_labels.to_string(),
+    );
+    w.field(
+        "multi_widget_text_select",
+        interaction.multi_widget_text_select.to_string(),
+    );
+    w.close("},");
+
+    let visuals = &style.visuals;
+    w.open("visuals: Visuals {");
+    w.field("dark_mode", visuals.dark_mode.to_string());
+    w.field(
+        "override_text_color",
+        option(&visuals.override_text_color, |color| color32(*color)),
+    );
+    w.open("widgets: Widgets {");
+    w.widget("noninteractive", &visuals.widgets.noninteractive);
+    w.widget("inactive", &visuals.widgets.inactive);
+    w.widget("hovered", &visuals.widgets.hovered);
+    w.widget("active", &visuals.widgets.active);
+    w.widget("open", &visuals.widgets.open);
+    w.close("},");
+    w.open("selection: Selection {");
+    w.field("bg_fill", color32(visuals.selection.bg_fill));
+    w.stroke("stroke", visuals.selection.stroke);
+    w.close("},");
+    w.field("hyperlink_color", color32(visuals.hyperlink_color));
+    w.field("faint_bg_color", color32(visuals.faint_bg_color));
+    w.field("extreme_bg_color", color32(visuals.extreme_bg_color));
+    w.field("code_bg_color", color32(visuals.code_bg_color));
+    w.field("warn_fg_color", color32(visuals.warn_fg_color));
+    w.field("error_fg_color", color32(visuals.error_fg_color));
+    w.field("window_rounding", rounding(visuals.window_rounding));
+    w.shadow("window_shadow", visuals.window_shadow);
+    w.field("window_fill", color32(visuals.window_fill));
+    w.stroke("window_stroke", visuals.window_stroke);
+    w.field("menu_rounding", rounding(visuals.menu_rounding));
+    w.field("panel_fill", color32(visuals.panel_fill));
+    w.shadow("popup_shadow", visuals.popup_shadow);
+    w.field("resize_corner_size", float(visuals.resize_corner_size));
+    w.field(
+        "text_cursor_preview",
+        visuals.text_cursor_preview.to_string(),
+    );
+    w.field("clip_rect_margin", float(visuals.clip_rect_margin));
+    w.field("button_frame", visuals.button_frame.to_string());
+    w.field(
+        "collapsing_header_frame",
+        visuals.collapsing_header_frame.to_string(),
+    );
+    w.field(
+        "indent_has_left_vline",
+        visuals.indent_has_left_vline.to_string(),
+    );
+    w.field("striped", visuals.striped.to_string());
+    w.field(
+        "slider_trailing_fill",
+        visuals.slider_trailing_fill.to_string(),
+    );
+    w.stroke("text_cursor", visuals.text_cursor);
+    w.field(
+        "interact_cursor",
+        option(&visuals.interact_cursor, |cursor| {
+            format!("egui::CursorIcon::{cursor:?}")
+        }),
+    );
+    w.field(
+        "image_loading_spinners",
+        visuals.image_loading_spinners.to_string(),
+    );
+    w.field(
+        "handle_shape",
+        match visuals.handle_shape {
+            HandleShape::Circle => "egui::style::HandleShape::Circle".to_owned(),
+            HandleShape::Rect { aspect_ratio } => format!(
+                "egui::style::HandleShape::Rect {{ aspect_ratio: {} }}",
+                float(aspect_ratio)
+            ),
+        },
+    );
+    w.field(
+        "window_highlight_topmost",
+        visuals.window_highlight_topmost.to_string(),
+    );
+    w.field(
+        "numeric_color_space",
+        match visuals.numeric_color_space {
+            NumericColorSpace::GammaByte => "egui::style::NumericColorSpace::GammaByte",
+            NumericColorSpace::Linear => "egui::style::NumericColorSpace::Linear",
+        }
+        .to_owned(),
+    );
+    w.close("},");
+
+    w.field("animation_time", float(style.animation_time));
+    w.field(
+        "explanation_tooltips",
+        style.explanation_tooltips.to_string(),
+    );
+    w.line("debug: Default::default(),");
+    w.field(
+        "always_scroll_the_only_direction",
+        style.always_scroll_the_only_direction.to_string(),
+    );
+    w.close("}");
+    w.close("}");
+    w.out
+}
+
+/// Writes lines of code indented by four spaces per level.
+#[derive(Default)]
+struct Writer {
+    out: String,
+    indent: usize,
+}
+
+impl Writer {
+    fn line(&mut self, line: &str) {
+        if line.is_empty() {
+            self.out.push('\n');
+        } else {
+            let _ = writeln!(self.out, "{:1$}{line}", "", self.indent * 4);
+        }
+    }
+
+    fn open(&mut self, line: &str) {
+        self.line(line);
+        self.indent += 1;
+    }
+
+    fn close(&mut self, line: &str) {
+        self.indent -= 1;
+        self.line(line);
+    }
+
+    fn field(&mut self, name: &str, value: String) {
+        self.line(&format!("{name}: {value},"));
+    }
+
+    fn margin(&mut self, name: &str, margin: &Margin) {
+        self.open(&format!("{name}: Margin {{"));
+        self.field("left", float(margin.left));
+        self.field("right", float(margin.right));
+        self.field("top", float(margin.top));
+        self.field("bottom", float(margin.bottom));
+        self.close("},");
+    }
+
+    fn stroke(&mut self, name: &str, stroke: Stroke) {
+        self.open(&format!("{name}: Stroke {{"));
+        self.field("width", float(stroke.width));
+        self.field("color", color32(stroke.color));
+        self.close("},");
+    }
+
+    fn shadow(&mut self, name: &str, shadow: Shadow) {
+        self.open(&format!("{name}: Shadow {{"));
+        self.field("extrusion", float(shadow.extrusion));
+        self.field("color", color32(shadow.color));
+        self.close("},");
+    }
+
+    fn widget(&mut self, name: &str, widget: &WidgetVisuals) {
+        self.open(&format!("{name}: WidgetVisuals {{"));
+        self.field("bg_fill", color32(widget.bg_fill));
+        self.field("weak_bg_fill", color32(widget.weak_bg_fill));
+        self.stroke("bg_stroke", widget.bg_stroke);
+        self.field("rounding", rounding(widget.rounding));
+        self.stroke("fg_stroke", widget.fg_stroke);
+        self.field("expansion", float(widget.expansion));
+        self.close("},");
+    }
+}
+
+/// A float literal that always has a decimal point, or the constant of a value without a literal.
+fn float(value: f32) -> String {
+    if value.is_nan() {
+        "f32::NAN".to_owned()
+    } else if value == f32::INFINITY {
+        "f32::INFINITY".to_owned()
+    } else if value == f32::NEG_INFINITY {
+        "f32::NEG_INFINITY".to_owned()
+    } else {
+        format!("{value:?}")
+    }
+}
+
+fn vec2(value: Vec2) -> String {
+    format!("Vec2 {{ x: {}, y: {} }}", float(value.x), float(value.y))
+}
+
+fn color32(color: Color32) -> String {
+    let [r, g, b, a] = color.to_array();
+    format!("Color32::from_rgba_premultiplied({r}, {g}, {b}, {a})")
+}
+
+fn rounding(rounding: Rounding) -> String {
+    format!(
+        "Rounding {{ nw: {}, ne: {}, sw: {}, se: {} }}",
+        float(rounding.nw),
+        float(rounding.ne),
+        float(rounding.sw),
+        float(rounding.se)
+    )
+}
+
+fn text_style(style: &TextStyle) -> String {
+    match style {
+        TextStyle::Name(name) => format!("TextStyle::Name({name:?}.into())"),
+        style => format!("TextStyle::{style}"),
+    }
+}
+
+fn font_id(font: &FontId) -> String {
+    let family = match &font.family {
+        FontFamily::Name(name) => format!("FontFamily::Name({name:?}.into())"),
+        family => format!("FontFamily::{family}"),
+    };
+    format!("FontId::new({}, {family})", float(font.size))
+}
+
+fn option<T>(value: &Option<T>, to_rust: impl Fn(&T) -> String) -> String {
+    match value {
+        Some(value) => format!("Some({})", to_rust(value)),
+        None => "None".to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn floats_are_valid_rust() {
+        assert_eq!(float(1.0), "1.0");
+        assert_eq!(float(0.083_333_336), "0.083333336");
+        assert_eq!(float(f32::NAN), "f32::NAN");
+        assert_eq!(float(f32::INFINITY), "f32::INFINITY");
+        assert_eq!(float(f32::NEG_INFINITY), "f32::NEG_INFINITY");
+    }
+}
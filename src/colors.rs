@@ -1,19 +1,21 @@
 use egui::{
     epaint::Shadow,
-    style::{Interaction, ScrollStyle, Selection, Spacing, WidgetVisuals, Widgets},
-    Color32, FontFamily, FontId, Margin, Rounding, Stroke, Style, TextStyle, Vec2, Visuals,
+    style::{
+        default_text_styles, Interaction, ScrollStyle, Selection, Spacing, WidgetVisuals, Widgets,
+    },
+    Color32, Margin, Rgba, Rounding, Stroke, Style, TextStyle, Vec2, Visuals,
 };
 
-use crate::imgui::COLOR_TEXT_WHITE;
+use crate::{imgui::COLOR_TEXT_WHITE, Palette};
 
 /// Converts hsv color space to rgb egui::Color32
 ///
 /// `h` is in the range of 0 <= h < 360.
-/// If h exceeds this range it is taken mod 360.
+/// If h exceeds this range it is taken mod 360, if it is not finite it is taken as 0.
 ///
 /// `s` and `v` are clamped to the range 0 <= x <= 1.
 pub fn from_hsv(h: f32, s: f32, v: f32) -> Color32 {
-    let h = h.abs() % 360.0;
+    let h = if h.is_finite() { h.abs() % 360.0 } else { 0.0 };
     let s = s.clamp(0.0, 1.0);
     let v = v.clamp(0.0, 1.0);
     // Formula from https://www.rapidtables.com/convert/color/hsv-to-rgb.html
@@ -21,12 +23,12 @@ pub fn from_hsv(h: f32, s: f32, v: f32) -> Color32 {
     let x = (1.0 - ((h / 60.0) % 2.0 - 1.0).abs()) * c;
     let m = v - c;
     let (r, g, b) = match h {
-        h if 0.0 <= h && h < 60.0 => (c, x, 0.0),
-        h if 60.0 <= h && h < 120.0 => (x, c, 0.0),
-        h if 120.0 <= h && h < 180.0 => (0.0, c, x),
-        h if 180.0 <= h && h < 240.0 => (0.0, x, c),
-        h if 240.0 <= h && h < 300.0 => (x, 0.0, c),
-        h if 300.0 <= h && h < 360.0 => (c, 0.0, x),
+        h if (0.0..60.0).contains(&h) => (c, x, 0.0),
+        h if (60.0..120.0).contains(&h) => (x, c, 0.0),
+        h if (120.0..180.0).contains(&h) => (0.0, c, x),
+        h if (180.0..240.0).contains(&h) => (0.0, x, c),
+        h if (240.0..300.0).contains(&h) => (x, 0.0, c),
+        h if (300.0..360.0).contains(&h) => (c, 0.0, x),
         _ => unreachable!(),
     };
     Color32::from_rgb(
@@ -36,32 +38,99 @@ pub fn from_hsv(h: f32, s: f32, v: f32) -> Color32 {
     )
 }
 
+/// Converts an egui::Color32 to hsv color space, the inverse of [`from_hsv`].
+///
+/// `h` is in the range of 0 <= h < 360, `s` and `v` in the range of 0 <= x <= 1.
+/// The color is expected to be opaque.
+pub fn to_hsv(color: Color32) -> (f32, f32, f32) {
+    let [r, g, b, _] = color.to_array().map(|c| c as f32 / 255.0);
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+    let h = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    let s = if max == 0.0 { 0.0 } else { delta / max };
+    (h % 360.0, s, max)
+}
+
 pub fn get_style(hue: f32, brightness: f32) -> Style {
-    let c1 = from_hsv(hue, 0.60, 0.27 * brightness);
-    let c2 = from_hsv(hue, 0.67, 0.42 * brightness);
-    let c3 = from_hsv(hue, 0.71, 0.67 * brightness);
-    let c4 = from_hsv(hue, 0.94, 0.96 * brightness);
-    let c5 = from_hsv(hue, 0.73, 0.98 * brightness);
+    get_style_from_palette(&get_palette(hue, brightness))
+}
 
-    Style {
+/// Get the palette of the color theme with the given hue and brightness.
+pub fn get_palette(hue: f32, brightness: f32) -> Palette {
+    get_palette_with_saturation(hue, 1.0, brightness)
+}
+
+/// Get the palette of the color theme with the given hue, saturation and brightness.
+///
+/// The saturation scales the saturation of the accents, `1.0` gives the palette of
+/// [`get_palette`].
+pub fn get_palette_with_saturation(hue: f32, saturation: f32, brightness: f32) -> Palette {
+    let c1 = from_hsv(hue, 0.60 * saturation, 0.27 * brightness);
+    let c2 = from_hsv(hue, 0.67 * saturation, 0.42 * brightness);
+    let c3 = from_hsv(hue, 0.71 * saturation, 0.67 * brightness);
+    let c4 = from_hsv(hue, 0.94 * saturation, 0.96 * brightness);
+    let c5 = from_hsv(hue, 0.73 * saturation, 0.98 * brightness);
+
+    Palette {
+        window_bg: Color32::from_rgba_premultiplied(15, 15, 15, 255),
+        widget_bg: Color32::from_gray(27),
+        extreme_bg: Color32::from_rgba_premultiplied(10, 10, 10, 255),
+        code_bg: Color32::from_rgba_premultiplied(64, 64, 64, 255),
+        border: Color32::from_gray(70),
+        text: COLOR_TEXT_WHITE,
+        text_active: Color32::WHITE,
+        frame_bg: c1,
+        frame_bg_hovered: c2,
+        frame_bg_active: c3,
+        button: c2,
+        button_hovered: c5,
+        button_active: c4,
+        selection: c4,
+        hyperlink: Color32::from_rgba_premultiplied(90, 170, 255, 255),
+        warn: Color32::from_rgba_premultiplied(255, 143, 0, 255),
+        error: Color32::from_rgba_premultiplied(255, 0, 0, 255),
+    }
+}
+
+/// Get the palette of a color theme whose active buttons and selection are `accent`.
+///
+/// The other accents are those of [`get_palette_with_saturation`] with the hue, saturation
+/// and brightness of `accent`.
+pub fn get_palette_from_accent(accent: Color32) -> Palette {
+    let (hue, saturation, value) = to_hsv(accent);
+    let palette = get_palette_with_saturation(hue, saturation / 0.94, value / 0.96);
+    // Converting back to rgb may be off by one step, so use the accent as is.
+    Palette {
+        button_active: accent,
+        selection: accent,
+        ..palette
+    }
+}
+
+/// Get the style of the color themes using the colors of `palette`, see [`Palette::apply`].
+///
+/// The style is in light mode if the window background is lighter than the text.
+pub fn get_style_from_palette(palette: &Palette) -> Style {
+    let dark_mode =
+        Rgba::from(palette.window_bg).intensity() < Rgba::from(palette.text).intensity();
+    let mut style = Style {
         // override the text styles here:
         // override_text_style: Option<TextStyle>
 
         // override the font id here:
         // override_font_id: Option<FontId>
 
-        // set your text styles here:
-        text_styles: {
-            use FontFamily::{Monospace, Proportional};
-            [
-                (TextStyle::Small, FontId::new(10.0, Proportional)),
-                (TextStyle::Body, FontId::new(12.0, Proportional)),
-                (TextStyle::Monospace, FontId::new(12.0, Monospace)),
-                (TextStyle::Button, FontId::new(12.0, Proportional)),
-                (TextStyle::Heading, FontId::new(16.0, Proportional)),
-            ]
-            .into()
-        },
+        // Text styles are left default. The typography is supplied
+        // by the font, see `Theme::get_style_with_font`.
+        text_styles: default_text_styles(),
 
         // set your drag value text style:
         // drag_value_text_style: TextStyle,
@@ -103,72 +172,72 @@ pub fn get_style(hue: f32, brightness: f32) -> Style {
             multi_widget_text_select: true,
         },
         visuals: Visuals {
-            dark_mode: true,
+            dark_mode,
             override_text_color: None,
             widgets: Widgets {
                 noninteractive: WidgetVisuals {
                     bg_fill: Color32::RED,
-                    weak_bg_fill: Color32::from_gray(27),
-                    bg_stroke: Stroke::new(1.0, Color32::from_gray(70)),
+                    weak_bg_fill: Color32::PLACEHOLDER,
+                    bg_stroke: Stroke::new(1.0, Color32::PLACEHOLDER),
                     rounding: Rounding::same(2.0),
-                    fg_stroke: Stroke::new(1.0, COLOR_TEXT_WHITE),
+                    fg_stroke: Stroke::new(1.0, Color32::PLACEHOLDER),
                     expansion: 0.0,
                 },
                 inactive: WidgetVisuals {
-                    bg_fill: c1,
-                    weak_bg_fill: c2,
+                    bg_fill: Color32::PLACEHOLDER,
+                    weak_bg_fill: Color32::PLACEHOLDER,
                     bg_stroke: Stroke::new(0.0, Color32::WHITE),
                     rounding: Rounding::same(2.0),
-                    fg_stroke: Stroke::new(1.0, COLOR_TEXT_WHITE),
+                    fg_stroke: Stroke::new(1.0, Color32::PLACEHOLDER),
                     expansion: 0.0,
                 },
                 hovered: WidgetVisuals {
-                    bg_fill: c2,
-                    weak_bg_fill: c5,
+                    bg_fill: Color32::PLACEHOLDER,
+                    weak_bg_fill: Color32::PLACEHOLDER,
                     bg_stroke: Stroke::new(0.0, Color32::WHITE),
                     rounding: Rounding::same(3.0),
-                    fg_stroke: Stroke::new(1.0, Color32::WHITE),
+                    fg_stroke: Stroke::new(1.0, Color32::PLACEHOLDER),
                     expansion: 1.0,
                 },
                 active: WidgetVisuals {
-                    bg_fill: c3,
-                    weak_bg_fill: c4,
+                    bg_fill: Color32::PLACEHOLDER,
+                    weak_bg_fill: Color32::PLACEHOLDER,
                     bg_stroke: Stroke::new(0.0, Color32::WHITE),
                     rounding: Rounding::same(2.0),
-                    fg_stroke: Stroke::new(1.0, Color32::WHITE),
+                    fg_stroke: Stroke::new(1.0, Color32::PLACEHOLDER),
                     expansion: 1.0,
                 },
                 open: WidgetVisuals {
-                    bg_fill: c2,
-                    weak_bg_fill: c5,
+                    bg_fill: Color32::PLACEHOLDER,
+                    weak_bg_fill: Color32::PLACEHOLDER,
                     bg_stroke: Stroke::new(0.0, Color32::WHITE),
                     rounding: Rounding::same(2.0),
-                    fg_stroke: Stroke::new(1.0, COLOR_TEXT_WHITE),
+                    fg_stroke: Stroke::new(1.0, Color32::PLACEHOLDER),
                     expansion: 0.0,
                 },
             },
             selection: Selection {
-                bg_fill: c4,
-                stroke: Stroke::new(1.0, Color32::WHITE),
+                bg_fill: Color32::PLACEHOLDER,
+                stroke: Stroke::new(1.0, Color32::PLACEHOLDER),
             },
-            hyperlink_color: Color32::from_rgba_premultiplied(90, 170, 255, 255),
+            hyperlink_color: Color32::PLACEHOLDER,
             faint_bg_color: Color32::from_rgba_premultiplied(5, 5, 5, 0),
-            extreme_bg_color: Color32::from_rgba_premultiplied(10, 10, 10, 255),
-            code_bg_color: Color32::from_rgba_premultiplied(64, 64, 64, 255),
-            warn_fg_color: Color32::from_rgba_premultiplied(255, 143, 0, 255),
-            error_fg_color: Color32::from_rgba_premultiplied(255, 0, 0, 255),
+            extreme_bg_color: Color32::PLACEHOLDER,
+            code_bg_color: Color32::PLACEHOLDER,
+            warn_fg_color: Color32::PLACEHOLDER,
+            error_fg_color: Color32::PLACEHOLDER,
             window_rounding: Rounding::same(0.0),
             window_shadow: Shadow {
                 extrusion: 0.0,
                 color: Color32::from_rgba_premultiplied(0, 0, 0, 0),
             },
-            window_fill: Color32::from_rgba_premultiplied(15, 15, 15, 255),
+            window_fill: Color32::PLACEHOLDER,
             window_stroke: Stroke {
                 width: 1.0,
-                color: Color32::from_rgba_premultiplied(70, 70, 70, 255),
+                color: Color32::PLACEHOLDER,
             },
             menu_rounding: Rounding::ZERO,
-            panel_fill: Color32::from_rgba_premultiplied(15, 15, 15, 255),
+            panel_fill: Color32::PLACEHOLDER,
             popup_shadow: Shadow {
                 extrusion: 0.0,
                 color: Color32::from_rgba_premultiplied(0, 0, 0, 0),
@@ -181,14 +250,18 @@ pub fn get_style(hue: f32, brightness: f32) -> Style {
             indent_has_left_vline: true,
             striped: false,
             slider_trailing_fill: true,
-            text_cursor: Stroke::new(2.0, Color32::from_rgb(192, 222, 255)),
+            text_cursor: if dark_mode {
+                Stroke::new(2.0, Color32::from_rgb(192, 222, 255))
+            } else {
+                Stroke::new(2.0, Color32::from_rgb(0, 83, 125))
+            },
             interact_cursor: None,
             image_loading_spinners: true,
             handle_shape: egui::style::HandleShape::Circle,
             window_highlight_topmost: false,
             numeric_color_space: egui::style::NumericColorSpace::GammaByte,
         },
-        animation_time: 0.0833333358168602,
+        animation_time: 0.083_333_336,
         explanation_tooltips: false,
         override_text_style: None,
         override_font_id: None,
@@ -196,5 +269,15 @@ pub fn get_style(hue: f32, brightness: f32) -> Style {
         wrap: None,
         debug: Default::default(),
         always_scroll_the_only_direction: false,
-    }
+    };
+    // The placeholder colors are those of the palette roles and the fields derived from them.
+    palette.apply(&mut style);
+    let visuals = &mut style.visuals;
+    let widgets = &mut visuals.widgets;
+    widgets.noninteractive.bg_stroke.color = palette.border;
+    widgets.open.bg_fill = palette.frame_bg_hovered;
+    widgets.open.weak_bg_fill = palette.button_hovered;
+    visuals.selection.stroke.color = palette.text_active;
+    visuals.panel_fill = palette.window_bg;
+    style
 }
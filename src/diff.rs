@@ -0,0 +1,153 @@
+//! Compare two styles field by field.
+//!
+//! ```no_run
+//! # use dear_egui::{diff::StyleDiff, Theme};
+//! # fn f(ctx: &egui::Context) {
+//! let diff = StyleDiff::new(&Theme::Sky.get_style(), &ctx.style());
+//! println!("{diff}");
+//! # }
+//! ```
+//! prints one line per changed field, with colors as hex:
+//! ```text
+//! visuals.selection.bg_fill: #005c80 -> #2f6b55
+//! visuals.widgets.inactive.rounding.nw: 2 -> 0
+//! ```
+
+use std::fmt::Display;
+
+use egui::Style;
+
+use crate::fields::{style_fields, FieldValue};
+
+/// A field that differs between two styles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    /// The path of the field, see [`crate::fields`].
+    pub path: String,
+    /// The old value, or `None` if the field is only in the new style.
+    pub old: Option<FieldValue>,
+    /// The new value, or `None` if the field is only in the old style.
+    pub new: Option<FieldValue>,
+}
+
+/// The fields that differ between two styles in the order of [`crate::fields::visit_style`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StyleDiff {
+    pub changes: Vec<FieldChange>,
+}
+
+impl StyleDiff {
+    /// List the fields of `new` that differ from `old`.
+    pub fn new(old: &Style, new: &Style) -> Self {
+        let old = style_fields(old);
+        let new = style_fields(new);
+        let find = |fields: &[(String, FieldValue)], path: &str| {
+            fields
+                .iter()
+                .find(|(field, _)| field == path)
+                .map(|(_, value)| value.clone())
+        };
+
+        let mut changes = Vec::new();
+        for (path, value) in &new {
+            let old = find(&old, path);
+            if old.as_ref() != Some(value) {
+                changes.push(FieldChange {
+                    path: path.clone(),
+                    old,
+                    new: Some(value.clone()),
+                });
+            }
+        }
+        for (path, value) in &old {
+            if find(&new, path).is_none() {
+                changes.push(FieldChange {
+                    path: path.clone(),
+                    old: Some(value.clone()),
+                    new: None,
+                });
+            }
+        }
+        Self { changes }
+    }
+
+    /// Whether the styles are the same.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Get the change of the field at `path`, if it changed.
+    pub fn get(&self, path: &str) -> Option<&FieldChange> {
+        self.changes.iter().find(|change| change.path == path)
+    }
+}
+
+/// Formats the change as `path: old -> new`.
+impl Display for FieldChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = |value: &Option<FieldValue>| match value {
+            Some(value) => value.to_string(),
+            None => "(missing)".to_owned(),
+        };
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.path,
+            value(&self.old),
+            value(&self.new)
+        )
+    }
+}
+
+/// Formats the changes one per line.
+impl Display for StyleDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{Color32, Rounding};
+
+    use super::*;
+    use crate::Theme;
+
+    #[test]
+    fn identical_styles_have_no_changes() {
+        for theme in Theme::ALL {
+            let style = theme.get_style();
+            assert!(StyleDiff::new(&style, &style.clone()).is_empty());
+        }
+    }
+
+    #[test]
+    fn changed_fields_are_listed() {
+        let old = Theme::Sky.get_style();
+        let mut new = old.clone();
+        new.visuals.selection.bg_fill = Color32::from_rgb(0x2f, 0x6b, 0x55);
+        new.visuals.widgets.inactive.rounding = Rounding {
+            nw: 0.0,
+            ..new.visuals.widgets.inactive.rounding
+        };
+        let diff = StyleDiff::new(&old, &new);
+        assert_eq!(diff.changes.len(), 2);
+        let change = diff.get("visuals.selection.bg_fill").unwrap();
+        assert_eq!(
+            change.old,
+            Some(FieldValue::Color(old.visuals.selection.bg_fill))
+        );
+        assert_eq!(
+            change.to_string(),
+            format!(
+                "visuals.selection.bg_fill: {} -> #2f6b55",
+                FieldValue::Color(old.visuals.selection.bg_fill)
+            )
+        );
+        assert!(diff.get("visuals.widgets.inactive.rounding.nw").is_some());
+        assert!(diff.get("visuals.widgets.inactive.rounding.ne").is_none());
+    }
+}
@@ -0,0 +1,294 @@
+use egui::{
+    style::{HandleShape, NumericColorSpace},
+    CollapsingHeader, ComboBox, Context, DragValue, FontFamily, Grid, ScrollArea, Style, TextEdit,
+    TextStyle, Ui, Window,
+};
+
+use crate::{
+    diff::StyleDiff,
+    fields::{visit_style, FieldMut},
+    theme_file::ThemeFile,
+    Font, FontSetup, Palette, PickerLayout, Theme, ThemePicker,
+};
+
+/// An editor for every field of the style of a context, like Dear ImGui's `ShowStyleEditor`.
+///
+/// Changes are applied to the context as they are made. The result can be
+/// reverted to the preset it started from or exported as a theme file or Rust code.
+/// ```no_run
+/// # use dear_egui::{Font, StyleEditor, Theme};
+/// # fn f(ctx: &egui::Context, show_editor: &mut bool) {
+/// let mut editor = StyleEditor::new(Theme::Forest, Font::OpenSans);
+/// // In the update loop:
+/// editor.window(ctx, show_editor);
+/// # }
+/// ```
+pub struct StyleEditor {
+    preset: Theme,
+    font: Font,
+    palette: Option<Palette>,
+    export: Option<String>,
+}
+
+impl StyleEditor {
+    /// Edit a style based on `preset`. Call [`StyleEditor::revert`] to apply the preset.
+    pub fn new(preset: Theme, font: Font) -> Self {
+        Self {
+            preset,
+            font,
+            palette: preset.palette(),
+            export: None,
+        }
+    }
+
+    /// The theme the edited style is based on.
+    pub fn preset(&self) -> Theme {
+        self.preset
+    }
+
+    /// The font of the edited style.
+    pub fn font(&self) -> Font {
+        self.font
+    }
+
+    /// Discard all changes and apply the preset and font.
+    pub fn revert(&mut self, ctx: &Context) {
+        self.palette = self.preset.palette();
+        crate::set_theme(ctx, self.preset, self.font);
+    }
+
+    /// The edited style as a theme file.
+    pub fn theme_file(&self, ctx: &Context) -> ThemeFile {
+        ThemeFile::from_style(self.preset, self.font, (*ctx.style()).clone())
+    }
+
+    /// Show the editor in a window.
+    pub fn window(&mut self, ctx: &Context, open: &mut bool) {
+        Window::new("Style Editor")
+            .open(open)
+            .default_width(360.0)
+            .vscroll(true)
+            .show(ctx, |ui| self.ui(ui));
+    }
+
+    /// Show the editor in `ui`.
+    pub fn ui(&mut self, ui: &mut Ui) {
+        let ctx = ui.ctx().clone();
+
+        ui.horizontal(|ui| {
+            ui.label("Preset:");
+            let mut preset = self.preset;
+            if ui
+                .add(ThemePicker::new(&mut preset).layout(PickerLayout::ComboBox))
+                .changed()
+            {
+                self.preset = preset;
+                self.revert(&ctx);
+            }
+            if ui.button("Revert").clicked() {
+                self.revert(&ctx);
+            }
+        });
+
+        let mut style = (*ctx.style()).clone();
+        let before = style.clone();
+
+        CollapsingHeader::new("Palette").show(ui, |ui| match &mut self.palette {
+            Some(palette) => {
+                let mut changed = Vec::new();
+                Grid::new("palette").num_columns(2).show(ui, |ui| {
+                    for (name, color) in palette.roles_mut() {
+                        ui.label(name);
+                        if ui.color_edit_button_srgba(color).changed() {
+                            changed.push(name);
+                        }
+                        ui.end_row();
+                    }
+                });
+                // Only the edited roles are written so that the other colors of the style are kept.
+                for name in changed {
+                    palette.apply_role(name, &mut style);
+                }
+            }
+            None => {
+                ui.label("This theme has no palette.");
+            }
+        });
+        CollapsingHeader::new("Widgets").show(ui, |ui| {
+            for state in ["noninteractive", "inactive", "hovered", "active", "open"] {
+                CollapsingHeader::new(state).show(ui, |ui| {
+                    let prefix = format!("visuals.widgets.{state}.");
+                    fields_grid(ui, &mut style, &|path| path.strip_prefix(&prefix));
+                });
+            }
+        });
+        CollapsingHeader::new("Visuals").show(ui, |ui| {
+            fields_grid(ui, &mut style, &|path| {
+                path.strip_prefix("visuals.")
+                    .filter(|path| !path.starts_with("widgets."))
+            });
+        });
+        CollapsingHeader::new("Spacing").show(ui, |ui| {
+            fields_grid(ui, &mut style, &|path| path.strip_prefix("spacing."));
+        });
+        CollapsingHeader::new("Interaction").show(ui, |ui| {
+            fields_grid(ui, &mut style, &|path| path.strip_prefix("interaction."));
+        });
+        CollapsingHeader::new("Fonts").show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Font:");
+                for font in Font::ALL {
+                    if ui
+                        .selectable_value(&mut self.font, font, font.name())
+                        .changed()
+                    {
+                        let font = FontSetup::new(font);
+                        ctx.set_fonts(font.font_definitions());
+                        font.apply_text_styles(&mut style);
+                    }
+                }
+            });
+            fields_grid(ui, &mut style, &|path| {
+                path.strip_prefix("text_styles.")
+                    .or((path == "drag_value_text_style").then_some(path))
+            });
+        });
+        CollapsingHeader::new("Other").show(ui, |ui| {
+            fields_grid(ui, &mut style, &|path| {
+                (!path.contains('.')).then_some(path)
+            });
+        });
+
+        CollapsingHeader::new("Changes").show(ui, |ui| {
+            let base = self.preset.get_style_with_font(&self.font.into());
+            let diff = StyleDiff::new(&base, &style);
+            if diff.is_empty() {
+                ui.label("No changes to the preset.");
+            }
+            for change in &diff.changes {
+                ui.monospace(change.to_string());
+            }
+        });
+
+        if style != before {
+            ctx.set_style(style);
+        }
+
+        ui.separator();
+        ui.horizontal(|ui| {
+            ui.label("Export:");
+            if ui.button("Theme file").clicked() {
+                self.export = Some(self.theme_file(&ctx).to_string());
+            }
+            if ui.button("Rust").clicked() {
+                self.export = Some(crate::codegen::style_to_rust(&ctx.style()));
+            }
+            if self.export.is_some() {
+                if ui.button("Copy").clicked() {
+                    ui.output_mut(|output| output.copied_text = self.export.clone().unwrap());
+                }
+                if ui.button("Close").clicked() {
+                    self.export = None;
+                }
+            }
+        });
+        if let Some(export) = &self.export {
+            ScrollArea::vertical()
+                .id_source("export")
+                .max_height(200.0)
+                .show(ui, |ui| {
+                    ui.add(
+                        TextEdit::multiline(&mut export.as_str())
+                            .code_editor()
+                            .desired_width(f32::INFINITY),
+                    );
+                });
+        }
+    }
+}
+
+/// Show an editor for every field for which `label` returns a label.
+fn fields_grid(ui: &mut Ui, style: &mut Style, label: &dyn Fn(&str) -> Option<&str>) {
+    let text_styles: Vec<TextStyle> = style.text_styles.keys().cloned().collect();
+    Grid::new(ui.next_auto_id())
+        .num_columns(2)
+        .striped(true)
+        .show(ui, |ui| {
+            visit_style(style, &mut |path, field| {
+                if let Some(label) = label(path) {
+                    ui.label(label);
+                    field_ui(ui, path, field, &text_styles);
+                    ui.end_row();
+                }
+            });
+        });
+}
+
+fn field_ui(ui: &mut Ui, path: &str, field: FieldMut<'_>, text_styles: &[TextStyle]) {
+    match field {
+        FieldMut::Float(value) => {
+            ui.add(DragValue::new(value).speed(0.1));
+        }
+        FieldMut::Bool(value) => {
+            ui.checkbox(value, "");
+        }
+        FieldMut::Color(color) => {
+            ui.color_edit_button_srgba(color);
+        }
+        FieldMut::OptionalColor(color) => {
+            ui.horizontal(|ui| {
+                let mut enabled = color.is_some();
+                if ui.checkbox(&mut enabled, "").changed() {
+                    *color = enabled.then_some(ui.visuals().text_color());
+                }
+                if let Some(color) = color {
+                    ui.color_edit_button_srgba(color);
+                }
+            });
+        }
+        FieldMut::Font(font) => {
+            ui.horizontal(|ui| {
+                ui.add(DragValue::new(&mut font.size).clamp_range(4.0..=100.0));
+                ComboBox::from_id_source(path)
+                    .selected_text(font.family.to_string())
+                    .show_ui(ui, |ui| {
+                        for family in [FontFamily::Proportional, FontFamily::Monospace] {
+                            let text = family.to_string();
+                            ui.selectable_value(&mut font.family, family, text);
+                        }
+                    });
+            });
+        }
+        FieldMut::TextStyle(value) => {
+            ComboBox::from_id_source(path)
+                .selected_text(value.to_string())
+                .show_ui(ui, |ui| {
+                    for text_style in text_styles {
+                        ui.selectable_value(value, text_style.clone(), text_style.to_string());
+                    }
+                });
+        }
+        FieldMut::HandleShape(shape) => {
+            ui.horizontal(|ui| {
+                ui.radio_value(shape, HandleShape::Circle, "Circle");
+                let is_rect = matches!(shape, HandleShape::Rect { .. });
+                if ui.radio(is_rect, "Rect").clicked() && !is_rect {
+                    *shape = HandleShape::Rect { aspect_ratio: 0.5 };
+                }
+                if let HandleShape::Rect { aspect_ratio } = shape {
+                    ui.add(
+                        DragValue::new(aspect_ratio)
+                            .clamp_range(0.1..=3.0)
+                            .speed(0.01),
+                    );
+                }
+            });
+        }
+        FieldMut::ColorSpace(space) => {
+            ui.horizontal(|ui| {
+                ui.radio_value(space, NumericColorSpace::GammaByte, "Gamma");
+                ui.radio_value(space, NumericColorSpace::Linear, "Linear");
+            });
+        }
+    }
+}
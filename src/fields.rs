@@ -0,0 +1,612 @@
+//! Every themeable field of a [`Style`] by its path.
+//!
+//! Fields are named by their path in the style, for example
+//! `visuals.widgets.inactive.bg_fill` or `spacing.item_spacing.x`.
+//! Vectors, margins, roundings, strokes and shadows are split into their
+//! components so that every field is a single value.
+//!
+//! The fields that control behavior rather than looks (`debug`, `wrap`,
+//! `override_text_style`, `override_font_id` and `visuals.interact_cursor`)
+//! are not part of a theme and are not listed.
+
+use std::fmt::Display;
+
+use egui::{
+    epaint::Shadow,
+    style::{HandleShape, NumericColorSpace, WidgetVisuals},
+    Color32, FontFamily, FontId, Margin, Rounding, Stroke, Style, TextStyle, Vec2,
+};
+
+/// A mutable reference to a field of a style.
+pub enum FieldMut<'a> {
+    Float(&'a mut f32),
+    Bool(&'a mut bool),
+    Color(&'a mut Color32),
+    OptionalColor(&'a mut Option<Color32>),
+    Font(&'a mut FontId),
+    TextStyle(&'a mut TextStyle),
+    HandleShape(&'a mut HandleShape),
+    ColorSpace(&'a mut NumericColorSpace),
+}
+
+/// The value of a field of a style.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Float(f32),
+    Bool(bool),
+    Color(Color32),
+    OptionalColor(Option<Color32>),
+    Font(FontId),
+    TextStyle(TextStyle),
+    HandleShape(HandleShape),
+    ColorSpace(NumericColorSpace),
+}
+
+impl FieldMut<'_> {
+    /// The current value of the field.
+    pub fn get(&self) -> FieldValue {
+        match self {
+            FieldMut::Float(value) => FieldValue::Float(**value),
+            FieldMut::Bool(value) => FieldValue::Bool(**value),
+            FieldMut::Color(value) => FieldValue::Color(**value),
+            FieldMut::OptionalColor(value) => FieldValue::OptionalColor(**value),
+            FieldMut::Font(value) => FieldValue::Font((*value).clone()),
+            FieldMut::TextStyle(value) => FieldValue::TextStyle((*value).clone()),
+            FieldMut::HandleShape(value) => FieldValue::HandleShape(**value),
+            FieldMut::ColorSpace(value) => FieldValue::ColorSpace(**value),
+        }
+    }
+
+    /// Parse `text` as a value of the type of this field and store it.
+    pub fn set_str(&mut self, text: &str) -> Result<(), String> {
+        let text = text.trim();
+        match self {
+            FieldMut::Float(value) => **value = parse_float(text)?,
+            FieldMut::Bool(value) => {
+                **value = text
+                    .parse()
+                    .map_err(|_| format!("Expected `true` or `false`, found `{text}`"))?
+            }
+            FieldMut::Color(value) => **value = parse_color(text)?,
+            FieldMut::OptionalColor(value) => {
+                **value = match text {
+                    "none" => None,
+                    text => Some(parse_color(text)?),
+                }
+            }
+            FieldMut::Font(value) => {
+                let (size, family) = text.split_once(' ').unwrap_or((text, ""));
+                **value = FontId::new(parse_float(size)?, parse_family(family.trim())?);
+            }
+            FieldMut::TextStyle(value) => **value = parse_text_style(text),
+            FieldMut::HandleShape(value) => {
+                **value = match text.split_once(' ') {
+                    None if text == "circle" => HandleShape::Circle,
+                    Some(("rect", aspect_ratio)) => HandleShape::Rect {
+                        aspect_ratio: parse_float(aspect_ratio.trim())?,
+                    },
+                    _ => {
+                        return Err(format!(
+                            "Expected `circle` or `rect <aspect ratio>`, found `{text}`"
+                        ))
+                    }
+                }
+            }
+            FieldMut::ColorSpace(value) => {
+                **value = match text {
+                    "gamma" => NumericColorSpace::GammaByte,
+                    "linear" => NumericColorSpace::Linear,
+                    _ => return Err(format!("Expected `gamma` or `linear`, found `{text}`")),
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats the value so that it can be parsed again with [`FieldMut::set_str`].
+///
+/// Colors are written as `#rrggbb` or `#rrggbbaa` in unmultiplied sRGB, see [`color_to_hex`].
+impl Display for FieldValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldValue::Float(value) => write!(f, "{value}"),
+            FieldValue::Bool(value) => write!(f, "{value}"),
+            FieldValue::Color(color) => write!(f, "{}", color_to_hex(*color)),
+            FieldValue::OptionalColor(None) => write!(f, "none"),
+            FieldValue::OptionalColor(Some(color)) => write!(f, "{}", color_to_hex(*color)),
+            FieldValue::Font(font) => write!(f, "{} {}", font.size, font.family),
+            FieldValue::TextStyle(style) => write!(f, "{style}"),
+            FieldValue::HandleShape(HandleShape::Circle) => write!(f, "circle"),
+            FieldValue::HandleShape(HandleShape::Rect { aspect_ratio }) => {
+                write!(f, "rect {aspect_ratio}")
+            }
+            FieldValue::ColorSpace(NumericColorSpace::GammaByte) => write!(f, "gamma"),
+            FieldValue::ColorSpace(NumericColorSpace::Linear) => write!(f, "linear"),
+        }
+    }
+}
+
+/// Format a color as `#rrggbb`, or `#rrggbbaa` if it is not opaque.
+///
+/// A translucent color that changes when converted to unmultiplied sRGB and back,
+/// like the additive `(5, 5, 5, 0)`, is written as `premultiplied #rrggbbaa`.
+pub fn color_to_hex(color: Color32) -> String {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    let hex = if a == 255 {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    };
+    if parse_color(&hex) == Ok(color) {
+        hex
+    } else {
+        let [r, g, b, a] = color.to_array();
+        format!("premultiplied #{r:02x}{g:02x}{b:02x}{a:02x}")
+    }
+}
+
+/// Parse a color written as `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`. The `#` is optional.
+///
+/// The components are unmultiplied sRGB, or premultiplied if the color is
+/// prefixed with `premultiplied`.
+pub fn parse_color(text: &str) -> Result<Color32, String> {
+    let error = || format!("Expected a color like `#rrggbb`, found `{text}`");
+    let (premultiplied, hex) = match text.trim().strip_prefix("premultiplied") {
+        Some(hex) => (true, hex.trim()),
+        None => (false, text.trim()),
+    };
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if !hex.is_ascii() {
+        return Err(error());
+    }
+    let components: Vec<u8> = match hex.len() {
+        3 | 4 => (0..hex.len())
+            .map(|index| u8::from_str_radix(&hex[index..index + 1], 16).map(|digit| digit * 17))
+            .collect::<Result<_, _>>(),
+        6 | 8 => (0..hex.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(&hex[index..index + 2], 16))
+            .collect::<Result<_, _>>(),
+        _ => return Err(error()),
+    }
+    .map_err(|_| error())?;
+    let (r, g, b) = (components[0], components[1], components[2]);
+    let a = components.get(3).copied().unwrap_or(255);
+    Ok(if premultiplied {
+        Color32::from_rgba_premultiplied(r, g, b, a)
+    } else {
+        Color32::from_rgba_unmultiplied(r, g, b, a)
+    })
+}
+
+fn parse_float(text: &str) -> Result<f32, String> {
+    text.parse()
+        .ok()
+        .filter(|value: &f32| value.is_finite())
+        .ok_or_else(|| format!("Expected a number, found `{text}`"))
+}
+
+fn parse_family(text: &str) -> Result<FontFamily, String> {
+    match text {
+        "Proportional" | "" => Ok(FontFamily::Proportional),
+        "Monospace" => Ok(FontFamily::Monospace),
+        name => Ok(FontFamily::Name(name.into())),
+    }
+}
+
+/// Parse the name of a text style. Unknown names are named text styles.
+pub fn parse_text_style(text: &str) -> TextStyle {
+    match text {
+        "Small" => TextStyle::Small,
+        "Body" => TextStyle::Body,
+        "Monospace" => TextStyle::Monospace,
+        "Button" => TextStyle::Button,
+        "Heading" => TextStyle::Heading,
+        name => TextStyle::Name(name.into()),
+    }
+}
+
+/// Call `f` with the path and a reference to every field of `style`.
+pub fn visit_style(style: &mut Style, f: &mut dyn FnMut(&str, FieldMut<'_>)) {
+    for (text_style, font) in style.text_styles.iter_mut() {
+        f(&format!("text_styles.{text_style}"), FieldMut::Font(font));
+    }
+    f(
+        "drag_value_text_style",
+        FieldMut::TextStyle(&mut style.drag_value_text_style),
+    );
+    f("animation_time", FieldMut::Float(&mut style.animation_time));
+    f(
+        "explanation_tooltips",
+        FieldMut::Bool(&mut style.explanation_tooltips),
+    );
+    f(
+        "always_scroll_the_only_direction",
+        FieldMut::Bool(&mut style.always_scroll_the_only_direction),
+    );
+    visit_spacing(&mut style.spacing, "spacing", f);
+    visit_interaction(&mut style.interaction, "interaction", f);
+    visit_visuals(&mut style.visuals, "visuals", f);
+}
+
+/// Call `f` with the path and a reference to every field of `spacing`.
+pub fn visit_spacing(
+    spacing: &mut egui::style::Spacing,
+    prefix: &str,
+    f: &mut dyn FnMut(&str, FieldMut<'_>),
+) {
+    let path = |name: &str| format!("{prefix}.{name}");
+    vec2(&mut spacing.item_spacing, &path("item_spacing"), f);
+    margin(&mut spacing.window_margin, &path("window_margin"), f);
+    vec2(&mut spacing.button_padding, &path("button_padding"), f);
+    margin(&mut spacing.menu_margin, &path("menu_margin"), f);
+    f(&path("indent"), FieldMut::Float(&mut spacing.indent));
+    vec2(&mut spacing.interact_size, &path("interact_size"), f);
+    for (name, value) in [
+        ("slider_width", &mut spacing.slider_width),
+        ("combo_width", &mut spacing.combo_width),
+        ("text_edit_width", &mut spacing.text_edit_width),
+        ("icon_width", &mut spacing.icon_width),
+        ("icon_width_inner", &mut spacing.icon_width_inner),
+        ("icon_spacing", &mut spacing.icon_spacing),
+        ("tooltip_width", &mut spacing.tooltip_width),
+        ("menu_width", &mut spacing.menu_width),
+        ("combo_height", &mut spacing.combo_height),
+    ] {
+        f(&path(name), FieldMut::Float(value));
+    }
+    f(
+        &path("indent_ends_with_horizontal_line"),
+        FieldMut::Bool(&mut spacing.indent_ends_with_horizontal_line),
+    );
+
+    let scroll = &mut spacing.scroll;
+    let path = |name: &str| format!("{prefix}.scroll.{name}");
+    f(&path("floating"), FieldMut::Bool(&mut scroll.floating));
+    for (name, value) in [
+        ("bar_width", &mut scroll.bar_width),
+        ("handle_min_length", &mut scroll.handle_min_length),
+        ("bar_inner_margin", &mut scroll.bar_inner_margin),
+        ("bar_outer_margin", &mut scroll.bar_outer_margin),
+        ("floating_width", &mut scroll.floating_width),
+        (
+            "floating_allocated_width",
+            &mut scroll.floating_allocated_width,
+        ),
+        (
+            "dormant_background_opacity",
+            &mut scroll.dormant_background_opacity,
+        ),
+        (
+            "active_background_opacity",
+            &mut scroll.active_background_opacity,
+        ),
+        (
+            "interact_background_opacity",
+            &mut scroll.interact_background_opacity,
+        ),
+        ("dormant_handle_opacity", &mut scroll.dormant_handle_opacity),
+        ("active_handle_opacity", &mut scroll.active_handle_opacity),
+        (
+            "interact_handle_opacity",
+            &mut scroll.interact_handle_opacity,
+        ),
+    ] {
+        f(&path(name), FieldMut::Float(value));
+    }
+    f(
+        &path("foreground_color"),
+        FieldMut::Bool(&mut scroll.foreground_color),
+    );
+}
+
+/// Call `f` with the path and a reference to every field of `interaction`.
+pub fn visit_interaction(
+    interaction: &mut egui::style::Interaction,
+    prefix: &str,
+    f: &mut dyn FnMut(&str, FieldMut<'_>),
+) {
+    let path = |name: &str| format!("{prefix}.{name}");
+    for (name, value) in [
+        (
+            "resize_grab_radius_side",
+            &mut interaction.resize_grab_radius_side,
+        ),
+        (
+            "resize_grab_radius_corner",
+            &mut interaction.resize_grab_radius_corner,
+        ),
+        ("tooltip_delay", &mut interaction.tooltip_delay),
+    ] {
+        f(&path(name), FieldMut::Float(value));
+    }
+    for (name, value) in [
+        (
+            "show_tooltips_only_when_still",
+            &mut interaction.show_tooltips_only_when_still,
+        ),
+        ("selectable_labels", &mut interaction.selectable_labels),
+        (
+            "multi_widget_text_select",
+            &mut interaction.multi_widget_text_select,
+        ),
+    ] {
+        f(&path(name), FieldMut::Bool(value));
+    }
+}
+
+/// Call `f` with the path and a reference to every field of `visuals`.
+pub fn visit_visuals(
+    visuals: &mut egui::Visuals,
+    prefix: &str,
+    f: &mut dyn FnMut(&str, FieldMut<'_>),
+) {
+    let path = |name: &str| format!("{prefix}.{name}");
+    f(&path("dark_mode"), FieldMut::Bool(&mut visuals.dark_mode));
+    f(
+        &path("override_text_color"),
+        FieldMut::OptionalColor(&mut visuals.override_text_color),
+    );
+    let widgets = &mut visuals.widgets;
+    for (name, widget) in [
+        ("noninteractive", &mut widgets.noninteractive),
+        ("inactive", &mut widgets.inactive),
+        ("hovered", &mut widgets.hovered),
+        ("active", &mut widgets.active),
+        ("open", &mut widgets.open),
+    ] {
+        visit_widget_visuals(widget, &path(&format!("widgets.{name}")), f);
+    }
+    f(
+        &path("selection.bg_fill"),
+        FieldMut::Color(&mut visuals.selection.bg_fill),
+    );
+    stroke(&mut visuals.selection.stroke, &path("selection.stroke"), f);
+    for (name, value) in [
+        ("hyperlink_color", &mut visuals.hyperlink_color),
+        ("faint_bg_color", &mut visuals.faint_bg_color),
+        ("extreme_bg_color", &mut visuals.extreme_bg_color),
+        ("code_bg_color", &mut visuals.code_bg_color),
+        ("warn_fg_color", &mut visuals.warn_fg_color),
+        ("error_fg_color", &mut visuals.error_fg_color),
+        ("window_fill", &mut visuals.window_fill),
+        ("panel_fill", &mut visuals.panel_fill),
+    ] {
+        f(&path(name), FieldMut::Color(value));
+    }
+    rounding(&mut visuals.window_rounding, &path("window_rounding"), f);
+    shadow(&mut visuals.window_shadow, &path("window_shadow"), f);
+    stroke(&mut visuals.window_stroke, &path("window_stroke"), f);
+    rounding(&mut visuals.menu_rounding, &path("menu_rounding"), f);
+    shadow(&mut visuals.popup_shadow, &path("popup_shadow"), f);
+    f(
+        &path("resize_corner_size"),
+        FieldMut::Float(&mut visuals.resize_corner_size),
+    );
+    stroke(&mut visuals.text_cursor, &path("text_cursor"), f);
+    f(
+        &path("clip_rect_margin"),
+        FieldMut::Float(&mut visuals.clip_rect_margin),
+    );
+    for (name, value) in [
+        (
+            "window_highlight_topmost",
+            &mut visuals.window_highlight_topmost,
+        ),
+        ("text_cursor_preview", &mut visuals.text_cursor_preview),
+        ("button_frame", &mut visuals.button_frame),
+        (
+            "collapsing_header_frame",
+            &mut visuals.collapsing_header_frame,
+        ),
+        ("indent_has_left_vline", &mut visuals.indent_has_left_vline),
+        ("striped", &mut visuals.striped),
+        ("slider_trailing_fill", &mut visuals.slider_trailing_fill),
+        (
+            "image_loading_spinners",
+            &mut visuals.image_loading_spinners,
+        ),
+    ] {
+        f(&path(name), FieldMut::Bool(value));
+    }
+    f(
+        &path("handle_shape"),
+        FieldMut::HandleShape(&mut visuals.handle_shape),
+    );
+    f(
+        &path("numeric_color_space"),
+        FieldMut::ColorSpace(&mut visuals.numeric_color_space),
+    );
+}
+
+/// Call `f` with the path and a reference to every field of `widget`.
+pub fn visit_widget_visuals(
+    widget: &mut WidgetVisuals,
+    prefix: &str,
+    f: &mut dyn FnMut(&str, FieldMut<'_>),
+) {
+    let path = |name: &str| format!("{prefix}.{name}");
+    f(&path("bg_fill"), FieldMut::Color(&mut widget.bg_fill));
+    f(
+        &path("weak_bg_fill"),
+        FieldMut::Color(&mut widget.weak_bg_fill),
+    );
+    stroke(&mut widget.bg_stroke, &path("bg_stroke"), f);
+    rounding(&mut widget.rounding, &path("rounding"), f);
+    stroke(&mut widget.fg_stroke, &path("fg_stroke"), f);
+    f(&path("expansion"), FieldMut::Float(&mut widget.expansion));
+}
+
+/// List the path and value of every field of `style`.
+pub fn style_fields(style: &Style) -> Vec<(String, FieldValue)> {
+    let mut style = style.clone();
+    let mut fields = Vec::new();
+    visit_style(&mut style, &mut |path, field| {
+        fields.push((path.to_owned(), field.get()))
+    });
+    fields
+}
+
+/// Parse `value` and store it in the field of `style` at `path`.
+///
+/// Text styles that are not in the style yet are added. The style is left as it is
+/// if the value can't be parsed.
+pub fn set_field(style: &mut Style, path: &str, value: &str) -> Result<(), String> {
+    if let Some(name) = path.strip_prefix("text_styles.") {
+        let mut font = FontId::proportional(14.0);
+        FieldMut::Font(&mut font).set_str(value)?;
+        style.text_styles.insert(parse_text_style(name), font);
+        return Ok(());
+    }
+    let mut result = Err(format!("Unknown field `{path}`"));
+    visit_style(style, &mut |field_path, mut field| {
+        if field_path == path {
+            result = field.set_str(value);
+        }
+    });
+    result
+}
+
+fn vec2(value: &mut Vec2, prefix: &str, f: &mut dyn FnMut(&str, FieldMut<'_>)) {
+    f(&format!("{prefix}.x"), FieldMut::Float(&mut value.x));
+    f(&format!("{prefix}.y"), FieldMut::Float(&mut value.y));
+}
+
+fn margin(value: &mut Margin, prefix: &str, f: &mut dyn FnMut(&str, FieldMut<'_>)) {
+    f(&format!("{prefix}.left"), FieldMut::Float(&mut value.left));
+    f(
+        &format!("{prefix}.right"),
+        FieldMut::Float(&mut value.right),
+    );
+    f(&format!("{prefix}.top"), FieldMut::Float(&mut value.top));
+    f(
+        &format!("{prefix}.bottom"),
+        FieldMut::Float(&mut value.bottom),
+    );
+}
+
+fn rounding(value: &mut Rounding, prefix: &str, f: &mut dyn FnMut(&str, FieldMut<'_>)) {
+    f(&format!("{prefix}.nw"), FieldMut::Float(&mut value.nw));
+    f(&format!("{prefix}.ne"), FieldMut::Float(&mut value.ne));
+    f(&format!("{prefix}.sw"), FieldMut::Float(&mut value.sw));
+    f(&format!("{prefix}.se"), FieldMut::Float(&mut value.se));
+}
+
+fn stroke(value: &mut Stroke, prefix: &str, f: &mut dyn FnMut(&str, FieldMut<'_>)) {
+    f(
+        &format!("{prefix}.width"),
+        FieldMut::Float(&mut value.width),
+    );
+    f(
+        &format!("{prefix}.color"),
+        FieldMut::Color(&mut value.color),
+    );
+}
+
+fn shadow(value: &mut Shadow, prefix: &str, f: &mut dyn FnMut(&str, FieldMut<'_>)) {
+    f(
+        &format!("{prefix}.extrusion"),
+        FieldMut::Float(&mut value.extrusion),
+    );
+    f(
+        &format!("{prefix}.color"),
+        FieldMut::Color(&mut value.color),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors_are_parsed_in_every_form() {
+        let orange = Color32::from_rgb(0xff, 0x88, 0x00);
+        assert_eq!(parse_color("#ff8800"), Ok(orange));
+        assert_eq!(parse_color("ff8800"), Ok(orange));
+        assert_eq!(parse_color(" #f80 "), Ok(orange));
+        assert_eq!(parse_color("#f80f"), Ok(orange));
+        assert_eq!(parse_color("#ff8800ff"), Ok(orange));
+        assert_eq!(
+            parse_color("#ff880080"),
+            Ok(Color32::from_rgba_unmultiplied(0xff, 0x88, 0x00, 0x80))
+        );
+        assert_eq!(
+            parse_color("premultiplied #05050500"),
+            Ok(Color32::from_rgba_premultiplied(5, 5, 5, 0))
+        );
+    }
+
+    #[test]
+    fn malformed_colors_are_rejected() {
+        for text in [
+            "",
+            "#",
+            "#ff888",
+            "#ff88000",
+            "#gg8800",
+            "#ff880\u{e9}",
+            "premultiplied",
+            "red",
+        ] {
+            assert!(parse_color(text).is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn colors_round_trip_through_hex() {
+        for a in [0, 1, 5, 64, 128, 254, 255] {
+            for value in (0..=a).step_by(3) {
+                let colors = [
+                    Color32::from_rgba_premultiplied(value, value, value, a),
+                    Color32::from_rgba_premultiplied(value, 0, a, a),
+                    Color32::from_rgba_unmultiplied(value, 90, 200, a),
+                ];
+                for color in colors {
+                    assert_eq!(parse_color(&color_to_hex(color)), Ok(color));
+                }
+            }
+        }
+        assert_eq!(
+            color_to_hex(Color32::from_rgba_premultiplied(5, 5, 5, 0)),
+            "premultiplied #05050500"
+        );
+        assert_eq!(color_to_hex(Color32::from_rgb(0, 83, 125)), "#00537d");
+    }
+
+    #[test]
+    fn fields_round_trip_through_strings() {
+        let mut style = crate::Theme::Cherry.get_style();
+        let fields = style_fields(&style);
+        for (path, value) in &fields {
+            set_field(&mut style, path, &value.to_string()).unwrap();
+        }
+        assert_eq!(style_fields(&style), fields);
+    }
+
+    #[test]
+    fn rejected_values_leave_the_style_unchanged() {
+        let mut style = crate::Theme::Cherry.get_style();
+        let fields = style_fields(&style);
+        for (path, value) in [
+            ("text_styles.Title", "big"),
+            ("text_styles.Title", "nan Proportional"),
+            ("animation_time", "nan"),
+            ("spacing.item_spacing.x", "inf"),
+            ("spacing.item_spacing.x", "-inf"),
+            ("visuals.window_fill", "#ff88zz"),
+            ("visuals.unknown", "1"),
+        ] {
+            assert!(
+                set_field(&mut style, path, value).is_err(),
+                "{path}: {value}"
+            );
+        }
+        assert_eq!(style_fields(&style), fields);
+        set_field(&mut style, "text_styles.Title", "20 Monospace").unwrap();
+        assert_eq!(
+            style.text_styles[&TextStyle::Name("Title".into())],
+            FontId::monospace(20.0)
+        );
+    }
+}
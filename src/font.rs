@@ -0,0 +1,423 @@
+use std::{collections::BTreeMap, io, path::Path};
+
+use egui::{FontData, FontDefinitions, FontFamily, FontId, FontTweak, Style, TextStyle};
+
+use crate::typography::{text_style, TypeRamp, TITLE};
+
+/// Embed a font from the `font` folder.
+/// With the `subset` feature the font trimmed by the build script is embedded instead.
+#[cfg(not(feature = "subset"))]
+macro_rules! font_bytes {
+    ($name:literal) => {
+        include_bytes!(concat!("../font/", $name))
+    };
+}
+#[cfg(feature = "subset")]
+macro_rules! font_bytes {
+    ($name:literal) => {
+        include_bytes!(concat!(env!("OUT_DIR"), "/", $name))
+    };
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Font {
+    #[default]
+    OpenSans,
+    ProggyClean,
+}
+
+impl Font {
+    /// All bundled fonts.
+    pub const ALL: [Font; 2] = [Font::OpenSans, Font::ProggyClean];
+
+    /// The display name of this font.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Font::OpenSans => "OpenSans",
+            Font::ProggyClean => "ProggyClean",
+        }
+    }
+
+    /// Get the font definition and text styles for this font.
+    ///
+    /// With the `icons` feature enabled the bundled icon font is added as a fallback
+    /// to every font family.
+    pub fn get_style(&self) -> (FontDefinitions, BTreeMap<TextStyle, FontId>) {
+        FontSetup::new(*self).get_style()
+    }
+
+    /// Get the text styles for this font.
+    pub fn text_styles(&self) -> BTreeMap<TextStyle, FontId> {
+        FontSetup::new(*self).text_styles()
+    }
+
+    /// The vertical metrics that center the text of this font in an 18px frame.
+    pub fn metrics(&self) -> FontMetrics {
+        match self {
+            // Proggy clean sits high in its row. Move it down by one pixel at 16px.
+            Font::ProggyClean => FontMetrics {
+                y_offset_factor: 1.0 / 16.0,
+                ..Default::default()
+            },
+            Font::OpenSans => FontMetrics {
+                y_offset_factor: -0.02,
+                ..Default::default()
+            },
+        }
+    }
+
+    fn font_definitions(&self, metrics: &FontMetrics) -> FontDefinitions {
+        let mut fonts = FontDefinitions::default();
+        match self {
+            Font::ProggyClean => {
+                //Install my own font (maybe supporting non-latin characters):
+                fonts.font_data.insert(
+                    "ProggyClean".to_owned(),
+                    FontData::from_static(font_bytes!("ProggyClean.ttf")).tweak(metrics.tweak()),
+                );
+                // Put my font first (highest priority):
+                fonts
+                    .families
+                    .get_mut(&FontFamily::Proportional)
+                    .unwrap()
+                    .insert(0, "ProggyClean".to_owned());
+                fonts
+                    .families
+                    .get_mut(&FontFamily::Monospace)
+                    .unwrap()
+                    .insert(0, "ProggyClean".to_owned());
+            }
+            Font::OpenSans => {
+                //Install my own font (maybe supporting non-latin characters):
+                fonts.font_data.insert(
+                    "OpenSans".to_owned(),
+                    FontData::from_static(font_bytes!("OpenSans-Regular.ttf"))
+                        .tweak(metrics.tweak()),
+                );
+                // Put my font first (highest priority):
+                fonts
+                    .families
+                    .get_mut(&FontFamily::Proportional)
+                    .unwrap()
+                    .insert(0, "OpenSans".to_owned());
+            }
+        }
+        fonts
+    }
+
+    fn font_text_styles(&self) -> BTreeMap<TextStyle, FontId> {
+        use FontFamily::{Monospace, Proportional};
+        match self {
+            Font::ProggyClean => [
+                (TextStyle::Small, FontId::new(16.0, Proportional)),
+                (TextStyle::Body, FontId::new(16.0, Proportional)),
+                (TextStyle::Monospace, FontId::new(16.0, Monospace)),
+                (TextStyle::Button, FontId::new(16.0, Proportional)),
+                (TextStyle::Heading, FontId::new(32.0, Proportional)),
+            ]
+            .into(),
+            Font::OpenSans => [
+                (TextStyle::Small, FontId::new(10.0, Proportional)),
+                (TextStyle::Body, FontId::new(12.0, Proportional)),
+                (TextStyle::Monospace, FontId::new(12.0, Monospace)),
+                (TextStyle::Button, FontId::new(12.0, Proportional)),
+                (TextStyle::Heading, FontId::new(16.0, Proportional)),
+            ]
+            .into(),
+        }
+    }
+}
+
+impl std::fmt::Display for Font {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The error returned when parsing a font fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFontError(String);
+
+impl std::fmt::Display for ParseFontError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown font `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseFontError {}
+
+/// Parses the name of a bundled font ignoring case.
+impl std::str::FromStr for Font {
+    type Err = ParseFontError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Font::ALL
+            .into_iter()
+            .find(|font| font.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| ParseFontError(s.to_owned()))
+    }
+}
+
+/// Vertical tuning of a font.
+#[derive(Clone, Copy, PartialEq)]
+pub struct FontMetrics {
+    /// Height of a row of text as a multiple of the font size.
+    ///
+    /// egui derives the row height from the font size. To change the line height the
+    /// text styles are enlarged by this factor and the glyphs of all fonts are scaled
+    /// back down by the same amount.
+    pub line_height: f32,
+    /// Shift the glyphs downwards by this fraction of the font size.
+    /// This is only a visual effect and does not change the layout.
+    pub y_offset_factor: f32,
+    /// Scale the glyphs by this much.
+    /// This is only a visual effect and does not change the layout.
+    pub scale: f32,
+}
+
+impl FontMetrics {
+    fn tweak(&self) -> FontTweak {
+        FontTweak {
+            scale: self.scale,
+            y_offset_factor: self.y_offset_factor,
+            y_offset: 0.0,
+            baseline_offset_factor: 0.0,
+        }
+    }
+}
+
+impl Default for FontMetrics {
+    fn default() -> Self {
+        Self {
+            line_height: 1.0,
+            y_offset_factor: 0.0,
+            scale: 1.0,
+        }
+    }
+}
+
+/// A font that is used for glyphs the theme font does not contain.
+#[derive(Clone)]
+pub struct Fallback {
+    pub name: String,
+    pub data: FontData,
+}
+
+impl Fallback {
+    pub fn new(name: impl Into<String>, data: FontData) -> Self {
+        Self {
+            name: name.into(),
+            data,
+        }
+    }
+
+    pub fn from_static(name: impl Into<String>, font: &'static [u8]) -> Self {
+        Self::new(name, FontData::from_static(font))
+    }
+
+    /// Load a fallback font from a font file.
+    /// The path is used as the name of the font.
+    pub fn from_path(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        Ok(Self::new(
+            path.to_string_lossy(),
+            FontData::from_owned(std::fs::read(path)?),
+        ))
+    }
+
+    /// Scale the glyphs of this font to match the size of the theme font.
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.data.tweak.scale = scale;
+        self
+    }
+
+    /// Shift the glyphs downwards by a fraction of the font size.
+    pub fn y_offset_factor(mut self, y_offset_factor: f32) -> Self {
+        self.data.tweak.y_offset_factor = y_offset_factor;
+        self
+    }
+
+    /// Shift the glyphs downwards by an amount of points.
+    pub fn y_offset(mut self, y_offset: f32) -> Self {
+        self.data.tweak.y_offset = y_offset;
+        self
+    }
+
+    /// Shift the baseline of a row using this font by a fraction of the font size.
+    pub fn baseline_offset_factor(mut self, baseline_offset_factor: f32) -> Self {
+        self.data.tweak.baseline_offset_factor = baseline_offset_factor;
+        self
+    }
+}
+
+/// Load every `.ttf`, `.otf` and `.ttc` file in `dir` as a fallback font.
+///
+/// The fonts are ordered by their file name.
+pub fn load_system_fonts(dir: impl AsRef<Path>) -> io::Result<Vec<Fallback>> {
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_font = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| matches!(ext.to_lowercase().as_str(), "ttf" | "otf" | "ttc"));
+        if path.is_file() && is_font {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    paths.into_iter().map(Fallback::from_path).collect()
+}
+
+/// A font together with an ordered chain of fallback fonts and a typographic ramp.
+///
+/// The fallbacks are tried in order after the font itself and before
+/// the default fonts that egui falls back to.
+#[derive(Clone)]
+pub struct FontSetup {
+    pub font: Font,
+    pub fallbacks: Vec<Fallback>,
+    /// The ramp that generates the size of every text style.
+    /// If `None` the text sizes of the font are used and the named text styles
+    /// are generated from its body size.
+    pub ramp: Option<TypeRamp>,
+    /// The vertical tuning of the font.
+    /// If `None` the tuning of the font is used, see [`Font::metrics`].
+    pub metrics: Option<FontMetrics>,
+}
+
+impl FontSetup {
+    pub fn new(font: Font) -> Self {
+        Self {
+            font,
+            fallbacks: Vec::new(),
+            ramp: None,
+            metrics: None,
+        }
+    }
+
+    /// Replace the vertical tuning of the font.
+    pub fn metrics(mut self, metrics: FontMetrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Generate all text sizes from a typographic ramp.
+    pub fn ramp(mut self, ramp: TypeRamp) -> Self {
+        self.ramp = Some(ramp);
+        self
+    }
+
+    /// Add a fallback to the end of the fallback chain.
+    pub fn fallback(mut self, fallback: Fallback) -> Self {
+        self.fallbacks.push(fallback);
+        self
+    }
+
+    /// Add all fonts in `dir` to the end of the fallback chain.
+    /// See [`load_system_fonts`].
+    pub fn system_fonts(mut self, dir: impl AsRef<Path>) -> io::Result<Self> {
+        self.fallbacks.extend(load_system_fonts(dir)?);
+        Ok(self)
+    }
+
+    /// Get the font definition and text styles for this setup.
+    pub fn get_style(&self) -> (FontDefinitions, BTreeMap<TextStyle, FontId>) {
+        (self.font_definitions(), self.text_styles())
+    }
+
+    /// Get the font definition with the fallback chain for this setup.
+    pub fn font_definitions(&self) -> FontDefinitions {
+        let metrics = self.get_metrics();
+        let mut fonts = self.font.font_definitions(&metrics);
+        for (index, fallback) in self.fallbacks.iter().enumerate() {
+            fonts
+                .font_data
+                .insert(fallback.name.clone(), fallback.data.clone());
+            // The first font in each family is the font of the theme.
+            for keys in fonts.families.values_mut() {
+                keys.insert((1 + index).min(keys.len()), fallback.name.clone());
+            }
+        }
+        #[cfg(feature = "icons")]
+        crate::icons::add_to_fonts(&mut fonts);
+        // The text styles are enlarged by the line height.
+        // Scale all glyphs back down to keep their visual size.
+        for data in fonts.font_data.values_mut() {
+            data.tweak.scale /= metrics.line_height;
+        }
+        fonts
+    }
+
+    /// Get the text styles for this setup.
+    pub fn text_styles(&self) -> BTreeMap<TextStyle, FontId> {
+        let mut text_styles = match self.ramp {
+            Some(ramp) => ramp.text_styles(),
+            None => {
+                let mut text_styles = self.font.font_text_styles();
+                let ramp = TypeRamp {
+                    base: text_styles[&TextStyle::Body].size,
+                    ..Default::default()
+                };
+                text_styles.extend(ramp.named_text_styles());
+                // Keep the title above the heading of the font.
+                let heading = text_styles[&TextStyle::Heading].size;
+                let title = text_styles.get_mut(&text_style(TITLE)).unwrap();
+                title.size = title.size.max((heading * ramp.ratio).round());
+                text_styles
+            }
+        };
+        let line_height = self.get_metrics().line_height;
+        for font_id in text_styles.values_mut() {
+            font_id.size *= line_height;
+        }
+        text_styles
+    }
+
+    fn get_metrics(&self) -> FontMetrics {
+        self.metrics.unwrap_or_else(|| self.font.metrics())
+    }
+
+    /// Replace the typography of a style with the text styles of this setup.
+    pub fn apply_text_styles(&self, style: &mut Style) {
+        style.text_styles = self.text_styles();
+    }
+}
+
+impl Default for FontSetup {
+    fn default() -> Self {
+        Self::new(Font::default())
+    }
+}
+
+impl From<Font> for FontSetup {
+    fn from(font: Font) -> Self {
+        Self::new(font)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::typography::{CAPTION, SUBHEADING};
+
+    #[test]
+    fn text_styles_grow_with_their_rank() {
+        for font in Font::ALL {
+            let text_styles = FontSetup::from(font).text_styles();
+            let sizes = [
+                text_style(CAPTION),
+                TextStyle::Body,
+                text_style(SUBHEADING),
+                TextStyle::Heading,
+                text_style(TITLE),
+            ]
+            .map(|text_style| text_styles[&text_style].size);
+            assert!(
+                sizes.windows(2).all(|pair| pair[0] <= pair[1]),
+                "{font:?}: {sizes:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Icon glyphs from the bundled [Phosphor](https://phosphoricons.com/) icon font.
+//!
+//! The icon font is merged as a fallback into every font family built by
+//! [`Font::get_style`](crate::Font::get_style), so icons can be mixed with
+//! regular text:
+//! ```no_run
+//! # use dear_egui::icons;
+//! # fn f(ui: &mut egui::Ui) {
+//! ui.button(format!("{} Save", icons::SAVE));
+//! # }
+//! ```
+
+use std::fmt::Display;
+
+use egui::{FontData, FontDefinitions, FontFamily, RichText, WidgetText};
+
+const FONT_NAME: &str = "Phosphor";
+
+/// A single icon glyph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Icon(char);
+
+impl Icon {
+    /// The codepoint of this icon in the icon font.
+    pub const fn char(&self) -> char {
+        self.0
+    }
+}
+
+impl Display for Icon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Icon> for RichText {
+    fn from(icon: Icon) -> Self {
+        RichText::new(icon.to_string())
+    }
+}
+
+impl From<Icon> for WidgetText {
+    fn from(icon: Icon) -> Self {
+        WidgetText::RichText(icon.into())
+    }
+}
+
+/// Add the icon font as the last fallback of the proportional and monospace families.
+pub(crate) fn add_to_fonts(fonts: &mut FontDefinitions) {
+    fonts.font_data.insert(
+        FONT_NAME.to_owned(),
+        FontData::from_static(include_bytes!("../font/Phosphor.ttf")),
+    );
+    for family in [FontFamily::Proportional, FontFamily::Monospace] {
+        if let Some(keys) = fonts.families.get_mut(&family) {
+            keys.push(FONT_NAME.to_owned());
+        }
+    }
+}
+
+/// Defines a constant per icon and [`ALL`] with every icon.
+macro_rules! icons {
+    ($($name:ident = $char:literal,)*) => {
+        $(pub const $name: Icon = Icon($char);)*
+
+        /// Every icon of this module.
+        pub const ALL: &[Icon] = &[$($name),*];
+    };
+}
+
+icons! {
+    // File
+    NEW = '\u{EAF6}',
+    OPEN = '\u{EB29}',
+    SAVE = '\u{EB1A}',
+    SAVE_AS = '\u{EB1B}',
+    EXPORT = '\u{EAD1}',
+    IMPORT = '\u{EAB6}',
+    UPLOAD = '\u{ED9E}',
+    FILE = '\u{EAE2}',
+    FOLDER = '\u{EB21}',
+    FOLDER_NEW = '\u{EB2A}',
+    EXIT = '\u{ECEA}',
+
+    // Edit
+    UNDO = '\u{E982}',
+    REDO = '\u{E983}',
+    CUT = '\u{ECBE}',
+    COPY = '\u{EA6B}',
+    PASTE = '\u{EA41}',
+    DELETE = '\u{ED8A}',
+    EDIT = '\u{EC59}',
+    SEARCH = '\u{EBDD}',
+    FILTER = '\u{EB3B}',
+    ADD = '\u{EC86}',
+    REMOVE = '\u{EBF8}',
+    REFRESH = '\u{E95D}',
+    EYEDROPPER = '\u{EAD4}',
+
+    // Status
+    CHECK = '\u{EA30}',
+    CHECK_CIRCLE = '\u{EA31}',
+    CLOSE = '\u{EDDB}',
+    ERROR = '\u{EDDC}',
+    WARNING = '\u{EDBF}',
+    INFO = '\u{EBA7}',
+    HELP = '\u{EC9F}',
+    SPINNER = '\u{ED16}',
+    BUG = '\u{E9DA}',
+
+    // Navigation
+    HOME = '\u{EB9C}',
+    MENU = '\u{EBCA}',
+    MORE = '\u{EAAF}',
+    GRIP = '\u{EAAE}',
+    CARET_UP = '\u{EA01}',
+    CARET_DOWN = '\u{E9FE}',
+    CARET_LEFT = '\u{E9FF}',
+    CARET_RIGHT = '\u{EA00}',
+    ARROW_UP = '\u{E97D}',
+    ARROW_DOWN = '\u{E93A}',
+    ARROW_LEFT = '\u{E953}',
+    ARROW_RIGHT = '\u{E95C}',
+    EXTERNAL_LINK = '\u{E972}',
+    LINK = '\u{EBC2}',
+    MAXIMIZE = '\u{EA70}',
+    MINIMIZE = '\u{EA6F}',
+    PIN = '\u{EC99}',
+
+    // Media
+    PLAY = '\u{EC7E}',
+    PAUSE = '\u{EC50}',
+    STOP = '\u{ED32}',
+    RECORD = '\u{ECAB}',
+    SKIP_BACK = '\u{ECEF}',
+    SKIP_FORWARD = '\u{ECF1}',
+    VOLUME = '\u{ED0C}',
+    MUTE = '\u{ED15}',
+    CAMERA = '\u{E9E9}',
+    IMAGE = '\u{EBA2}',
+
+    // Settings
+    SETTINGS = '\u{EB45}',
+    SLIDERS = '\u{ECF6}',
+    WRENCH = '\u{EDDA}',
+    PALETTE = '\u{EC42}',
+    BRUSH = '\u{EC3D}',
+    FONT = '\u{ED57}',
+    SUN = '\u{ED3E}',
+    MOON = '\u{EBFE}',
+    LOCK = '\u{EBD1}',
+    UNLOCK = '\u{EBD7}',
+    VISIBLE = '\u{EAD2}',
+    HIDDEN = '\u{EAD7}',
+
+    // Objects
+    CODE = '\u{EA58}',
+    TERMINAL = '\u{ED54}',
+    CUBE = '\u{EA7A}',
+    LAYERS = '\u{ED23}',
+    TREE = '\u{ED90}',
+    TABLE = '\u{ED48}',
+    GRID = '\u{EB6B}',
+    CHART_BAR = '\u{EA17}',
+    CHART_LINE = '\u{EA1A}',
+    DATABASE = '\u{EA8F}',
+    GLOBE = '\u{EB58}',
+    CLOCK = '\u{EA42}',
+    CALENDAR = '\u{E9E3}',
+    BELL = '\u{E9AF}',
+    USER = '\u{EDA0}',
+    KEY = '\u{EBAE}',
+    TAG = '\u{ED4A}',
+    BOOKMARK = '\u{E9C3}',
+    FLAG = '\u{EB13}',
+    STAR = '\u{ED28}',
+    HEART = '\u{EB89}',
+    LIGHTNING = '\u{EBBD}',
+    CURSOR = '\u{EA8B}',
+    CROSSHAIR = '\u{EA76}',
+    CIRCLE = '\u{EA37}',
+    SQUARE = '\u{ED1C}',
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_icon_has_a_glyph() {
+        let face = ttf_parser::Face::parse(include_bytes!("../font/Phosphor.ttf"), 0).unwrap();
+        for icon in ALL {
+            assert!(
+                face.glyph_index(icon.char()).is_some(),
+                "U+{:04X} is not in the icon font",
+                icon.char() as u32
+            );
+        }
+    }
+}
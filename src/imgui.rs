@@ -6,6 +6,8 @@ use egui::{
     Color32, Margin, Rounding, Stroke, Style, TextStyle, Vec2, Visuals,
 };
 
+use crate::Palette;
+
 pub const COLOR_BACKGROUND: Color32 = Color32::from_rgb(15, 15, 15);
 pub const COLOR_BORDER: Color32 = Color32::from_rgb(63, 63, 72);
 
@@ -24,6 +26,29 @@ pub const STROKE_WHITE: Stroke = Stroke {
     color: Color32::WHITE,
 };
 
+/// Get the colors of the imgui theme by their role.
+pub fn get_palette() -> Palette {
+    Palette {
+        window_bg: COLOR_BACKGROUND,
+        widget_bg: Color32::from_rgba_premultiplied(27, 27, 27, 255),
+        extreme_bg: Color32::from_rgba_premultiplied(10, 10, 10, 255),
+        code_bg: Color32::from_rgba_premultiplied(10, 10, 10, 255),
+        border: COLOR_BORDER,
+        text: COLOR_TEXT_WHITE,
+        text_active: Color32::WHITE,
+        frame_bg: COLOR_INACTIVE,
+        frame_bg_hovered: COLOR_HOVERED,
+        frame_bg_active: COLOR_ACTIVE,
+        button: COLOR_INACTIVE,
+        button_hovered: COLOR_HOVERED,
+        button_active: COLOR_ACTIVE,
+        selection: Color32::from_rgba_premultiplied(0, 92, 128, 255),
+        hyperlink: Color32::from_rgba_premultiplied(90, 170, 255, 255),
+        warn: Color32::from_rgba_premultiplied(255, 143, 0, 255),
+        error: Color32::from_rgba_premultiplied(255, 0, 0, 255),
+    }
+}
+
 pub fn get_style() -> Style {
     Style {
         // override the text styles here:
@@ -174,7 +199,7 @@ pub fn get_style() -> Style {
             window_highlight_topmost: false,
             numeric_color_space: egui::style::NumericColorSpace::GammaByte,
         },
-        animation_time: 0.0833333358168602,
+        animation_time: 0.083_333_336,
         explanation_tooltips: false,
         override_text_style: None,
         override_font_id: None,
@@ -0,0 +1,400 @@
+//! Dear ImGui's color slots mapped onto an egui [`Style`].
+//!
+//! egui has fewer colors than ImGui. Some slots drive egui fields of their own,
+//! some are drawn by egui with the color of another slot, and some are not drawn
+//! by egui at all. [`ImGuiCol::has_field`] tells them apart:
+//!
+//! | Slot | egui fields |
+//! |---|---|
+//! | `Text` | `fg_stroke` of the noninteractive, inactive and open widgets |
+//! | `WindowBg` | `window_fill`, `panel_fill` |
+//! | `Border` | `window_stroke`, `bg_stroke` of the noninteractive widgets |
+//! | `BorderShadow` | `window_shadow` |
+//! | `FrameBg`, `FrameBgHovered`, `FrameBgActive` | `bg_fill` of the inactive, hovered and active widgets |
+//! | `TitleBgActive` | `weak_bg_fill` of the open widgets |
+//! | `ScrollbarBg` | `extreme_bg_color` |
+//! | `Button`, `ButtonHovered`, `ButtonActive` | `weak_bg_fill` of the inactive, hovered and active widgets |
+//! | `TableRowBgAlt` | `faint_bg_color` |
+//! | `TextSelectedBg` | `selection.bg_fill` |
+//!
+//! Slots that egui draws with the color of another slot:
+//!
+//! | Slot | Drawn with |
+//! |---|---|
+//! | `TextDisabled` | `Text`, faded |
+//! | `PopupBg` | `WindowBg` |
+//! | `TitleBg`, `TitleBgCollapsed` | `TitleBgActive` |
+//! | `MenuBarBg` | `WindowBg` |
+//! | `ScrollbarGrab*`, `SliderGrab*` | `FrameBg*` |
+//! | `CheckMark` | `Text` |
+//! | `Header`, `DockingPreview` | `TextSelectedBg` |
+//! | `HeaderHovered`, `HeaderActive` | `ButtonHovered`, `ButtonActive` |
+//! | `Separator*`, `TableBorder*` | `Border` |
+//! | `ResizeGrip*` | `Text` |
+//! | `Tab*`, `TableHeaderBg` | `Button*` |
+//! | `NavHighlight` | the `selection.stroke` |
+//!
+//! The remaining slots (`ChildBg`, `DockingEmptyBg`, `Plot*`, `TableRowBg`,
+//! `DragDropTarget`, `NavWindowing*` and `ModalWindowDimBg`) are not drawn by egui
+//! widgets and are only honored by widgets that read them with [`get`].
+//! [`get`] returns Dear ImGui's dark default for them.
+
+use egui::{Color32, Style};
+
+/// The color slots of Dear ImGui's `ImGuiCol_` enum, in the same order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImGuiCol {
+    Text,
+    TextDisabled,
+    WindowBg,
+    ChildBg,
+    PopupBg,
+    Border,
+    BorderShadow,
+    FrameBg,
+    FrameBgHovered,
+    FrameBgActive,
+    TitleBg,
+    TitleBgActive,
+    TitleBgCollapsed,
+    MenuBarBg,
+    ScrollbarBg,
+    ScrollbarGrab,
+    ScrollbarGrabHovered,
+    ScrollbarGrabActive,
+    CheckMark,
+    SliderGrab,
+    SliderGrabActive,
+    Button,
+    ButtonHovered,
+    ButtonActive,
+    Header,
+    HeaderHovered,
+    HeaderActive,
+    Separator,
+    SeparatorHovered,
+    SeparatorActive,
+    ResizeGrip,
+    ResizeGripHovered,
+    ResizeGripActive,
+    Tab,
+    TabHovered,
+    TabActive,
+    TabUnfocused,
+    TabUnfocusedActive,
+    DockingPreview,
+    DockingEmptyBg,
+    PlotLines,
+    PlotLinesHovered,
+    PlotHistogram,
+    PlotHistogramHovered,
+    TableHeaderBg,
+    TableBorderStrong,
+    TableBorderLight,
+    TableRowBg,
+    TableRowBgAlt,
+    TextSelectedBg,
+    DragDropTarget,
+    NavHighlight,
+    NavWindowingHighlight,
+    NavWindowingDimBg,
+    ModalWindowDimBg,
+}
+
+impl ImGuiCol {
+    /// All slots in the order of `ImGuiCol_`.
+    pub const ALL: [ImGuiCol; 55] = [
+        ImGuiCol::Text,
+        ImGuiCol::TextDisabled,
+        ImGuiCol::WindowBg,
+        ImGuiCol::ChildBg,
+        ImGuiCol::PopupBg,
+        ImGuiCol::Border,
+        ImGuiCol::BorderShadow,
+        ImGuiCol::FrameBg,
+        ImGuiCol::FrameBgHovered,
+        ImGuiCol::FrameBgActive,
+        ImGuiCol::TitleBg,
+        ImGuiCol::TitleBgActive,
+        ImGuiCol::TitleBgCollapsed,
+        ImGuiCol::MenuBarBg,
+        ImGuiCol::ScrollbarBg,
+        ImGuiCol::ScrollbarGrab,
+        ImGuiCol::ScrollbarGrabHovered,
+        ImGuiCol::ScrollbarGrabActive,
+        ImGuiCol::CheckMark,
+        ImGuiCol::SliderGrab,
+        ImGuiCol::SliderGrabActive,
+        ImGuiCol::Button,
+        ImGuiCol::ButtonHovered,
+        ImGuiCol::ButtonActive,
+        ImGuiCol::Header,
+        ImGuiCol::HeaderHovered,
+        ImGuiCol::HeaderActive,
+        ImGuiCol::Separator,
+        ImGuiCol::SeparatorHovered,
+        ImGuiCol::SeparatorActive,
+        ImGuiCol::ResizeGrip,
+        ImGuiCol::ResizeGripHovered,
+        ImGuiCol::ResizeGripActive,
+        ImGuiCol::Tab,
+        ImGuiCol::TabHovered,
+        ImGuiCol::TabActive,
+        ImGuiCol::TabUnfocused,
+        ImGuiCol::TabUnfocusedActive,
+        ImGuiCol::DockingPreview,
+        ImGuiCol::DockingEmptyBg,
+        ImGuiCol::PlotLines,
+        ImGuiCol::PlotLinesHovered,
+        ImGuiCol::PlotHistogram,
+        ImGuiCol::PlotHistogramHovered,
+        ImGuiCol::TableHeaderBg,
+        ImGuiCol::TableBorderStrong,
+        ImGuiCol::TableBorderLight,
+        ImGuiCol::TableRowBg,
+        ImGuiCol::TableRowBgAlt,
+        ImGuiCol::TextSelectedBg,
+        ImGuiCol::DragDropTarget,
+        ImGuiCol::NavHighlight,
+        ImGuiCol::NavWindowingHighlight,
+        ImGuiCol::NavWindowingDimBg,
+        ImGuiCol::ModalWindowDimBg,
+    ];
+
+    /// The name of the slot without the `ImGuiCol_` prefix.
+    pub fn name(self) -> &'static str {
+        NAMES[self as usize]
+    }
+
+    /// Find a slot by its name, with or without the `ImGuiCol_` prefix.
+    pub fn from_name(name: &str) -> Option<ImGuiCol> {
+        let name = name.trim();
+        let name = name.strip_prefix("ImGuiCol_").unwrap_or(name);
+        NAMES
+            .iter()
+            .position(|slot| *slot == name)
+            .map(|index| ImGuiCol::ALL[index])
+    }
+
+    /// Whether the slot drives egui fields of its own, so that [`set`] changes the style.
+    pub fn has_field(self) -> bool {
+        matches!(
+            self,
+            ImGuiCol::Text
+                | ImGuiCol::WindowBg
+                | ImGuiCol::Border
+                | ImGuiCol::BorderShadow
+                | ImGuiCol::FrameBg
+                | ImGuiCol::FrameBgHovered
+                | ImGuiCol::FrameBgActive
+                | ImGuiCol::TitleBgActive
+                | ImGuiCol::ScrollbarBg
+                | ImGuiCol::Button
+                | ImGuiCol::ButtonHovered
+                | ImGuiCol::ButtonActive
+                | ImGuiCol::TableRowBgAlt
+                | ImGuiCol::TextSelectedBg
+        )
+    }
+}
+
+const NAMES: [&str; 55] = [
+    "Text",
+    "TextDisabled",
+    "WindowBg",
+    "ChildBg",
+    "PopupBg",
+    "Border",
+    "BorderShadow",
+    "FrameBg",
+    "FrameBgHovered",
+    "FrameBgActive",
+    "TitleBg",
+    "TitleBgActive",
+    "TitleBgCollapsed",
+    "MenuBarBg",
+    "ScrollbarBg",
+    "ScrollbarGrab",
+    "ScrollbarGrabHovered",
+    "ScrollbarGrabActive",
+    "CheckMark",
+    "SliderGrab",
+    "SliderGrabActive",
+    "Button",
+    "ButtonHovered",
+    "ButtonActive",
+    "Header",
+    "HeaderHovered",
+    "HeaderActive",
+    "Separator",
+    "SeparatorHovered",
+    "SeparatorActive",
+    "ResizeGrip",
+    "ResizeGripHovered",
+    "ResizeGripActive",
+    "Tab",
+    "TabHovered",
+    "TabActive",
+    "TabUnfocused",
+    "TabUnfocusedActive",
+    "DockingPreview",
+    "DockingEmptyBg",
+    "PlotLines",
+    "PlotLinesHovered",
+    "PlotHistogram",
+    "PlotHistogramHovered",
+    "TableHeaderBg",
+    "TableBorderStrong",
+    "TableBorderLight",
+    "TableRowBg",
+    "TableRowBgAlt",
+    "TextSelectedBg",
+    "DragDropTarget",
+    "NavHighlight",
+    "NavWindowingHighlight",
+    "NavWindowingDimBg",
+    "ModalWindowDimBg",
+];
+
+/// A color from the float components of an `ImVec4`.
+///
+/// Dear ImGui stores sRGB components as floats and converts them to bytes without
+/// a gamma curve, so they are converted the same way here.
+pub fn imvec4(r: f32, g: f32, b: f32, a: f32) -> Color32 {
+    let byte = |value: f32| (value.clamp(0.0, 1.0) * 255.0 + 0.5) as u8;
+    Color32::from_rgba_unmultiplied(byte(r), byte(g), byte(b), byte(a))
+}
+
+/// Get the color of a slot.
+///
+/// Slots drawn with another slot's color return that color and slots that egui
+/// does not draw return Dear ImGui's dark default. See the [module docs](self).
+pub fn get(style: &Style, col: ImGuiCol) -> Color32 {
+    let visuals = &style.visuals;
+    let widgets = &visuals.widgets;
+    match col {
+        ImGuiCol::Text | ImGuiCol::CheckMark | ImGuiCol::ResizeGrip => {
+            widgets.noninteractive.fg_stroke.color
+        }
+        ImGuiCol::TextDisabled => visuals.weak_text_color(),
+        ImGuiCol::WindowBg | ImGuiCol::PopupBg | ImGuiCol::MenuBarBg => visuals.window_fill,
+        ImGuiCol::Border
+        | ImGuiCol::Separator
+        | ImGuiCol::SeparatorHovered
+        | ImGuiCol::SeparatorActive
+        | ImGuiCol::TableBorderStrong
+        | ImGuiCol::TableBorderLight => visuals.window_stroke.color,
+        ImGuiCol::BorderShadow => visuals.window_shadow.color,
+        ImGuiCol::FrameBg | ImGuiCol::ScrollbarGrab | ImGuiCol::SliderGrab => {
+            widgets.inactive.bg_fill
+        }
+        ImGuiCol::FrameBgHovered | ImGuiCol::ScrollbarGrabHovered => widgets.hovered.bg_fill,
+        ImGuiCol::FrameBgActive | ImGuiCol::ScrollbarGrabActive | ImGuiCol::SliderGrabActive => {
+            widgets.active.bg_fill
+        }
+        ImGuiCol::TitleBg | ImGuiCol::TitleBgActive | ImGuiCol::TitleBgCollapsed => {
+            widgets.open.weak_bg_fill
+        }
+        ImGuiCol::ScrollbarBg => visuals.extreme_bg_color,
+        ImGuiCol::Button
+        | ImGuiCol::Tab
+        | ImGuiCol::TabUnfocused
+        | ImGuiCol::TabUnfocusedActive
+        | ImGuiCol::TableHeaderBg => widgets.inactive.weak_bg_fill,
+        ImGuiCol::ButtonHovered | ImGuiCol::HeaderHovered | ImGuiCol::TabHovered => {
+            widgets.hovered.weak_bg_fill
+        }
+        ImGuiCol::ButtonActive | ImGuiCol::HeaderActive | ImGuiCol::TabActive => {
+            widgets.active.weak_bg_fill
+        }
+        ImGuiCol::ResizeGripHovered => widgets.hovered.fg_stroke.color,
+        ImGuiCol::ResizeGripActive => widgets.active.fg_stroke.color,
+        ImGuiCol::TextSelectedBg | ImGuiCol::Header | ImGuiCol::DockingPreview => {
+            visuals.selection.bg_fill
+        }
+        ImGuiCol::TableRowBgAlt => visuals.faint_bg_color,
+        ImGuiCol::NavHighlight => visuals.selection.stroke.color,
+        ImGuiCol::ChildBg | ImGuiCol::TableRowBg => Color32::TRANSPARENT,
+        ImGuiCol::DockingEmptyBg => imvec4(0.20, 0.20, 0.20, 1.00),
+        ImGuiCol::PlotLines => imvec4(0.61, 0.61, 0.61, 1.00),
+        ImGuiCol::PlotLinesHovered => imvec4(1.00, 0.43, 0.35, 1.00),
+        ImGuiCol::PlotHistogram => imvec4(0.90, 0.70, 0.00, 1.00),
+        ImGuiCol::PlotHistogramHovered => imvec4(1.00, 0.60, 0.00, 1.00),
+        ImGuiCol::DragDropTarget => imvec4(1.00, 1.00, 0.00, 0.90),
+        ImGuiCol::NavWindowingHighlight => imvec4(1.00, 1.00, 1.00, 0.70),
+        ImGuiCol::NavWindowingDimBg => imvec4(0.80, 0.80, 0.80, 0.20),
+        ImGuiCol::ModalWindowDimBg => imvec4(0.80, 0.80, 0.80, 0.35),
+    }
+}
+
+/// Set the color of a slot in every egui field it drives.
+///
+/// Slots without fields of their own are ignored, see [`ImGuiCol::has_field`].
+pub fn set(style: &mut Style, col: ImGuiCol, color: Color32) {
+    for_each_field(style, col, &mut |field| *field = color);
+}
+
+/// Call `f` with every egui field a slot drives.
+pub(crate) fn for_each_field(style: &mut Style, col: ImGuiCol, f: &mut dyn FnMut(&mut Color32)) {
+    let visuals = &mut style.visuals;
+    let widgets = &mut visuals.widgets;
+    match col {
+        ImGuiCol::Text => {
+            f(&mut widgets.noninteractive.fg_stroke.color);
+            f(&mut widgets.inactive.fg_stroke.color);
+            f(&mut widgets.open.fg_stroke.color);
+        }
+        ImGuiCol::WindowBg => {
+            f(&mut visuals.window_fill);
+            f(&mut visuals.panel_fill);
+        }
+        ImGuiCol::Border => {
+            f(&mut visuals.window_stroke.color);
+            f(&mut widgets.noninteractive.bg_stroke.color);
+        }
+        ImGuiCol::BorderShadow => f(&mut visuals.window_shadow.color),
+        ImGuiCol::FrameBg => f(&mut widgets.inactive.bg_fill),
+        ImGuiCol::FrameBgHovered => f(&mut widgets.hovered.bg_fill),
+        ImGuiCol::FrameBgActive => f(&mut widgets.active.bg_fill),
+        ImGuiCol::TitleBgActive => f(&mut widgets.open.weak_bg_fill),
+        ImGuiCol::ScrollbarBg => f(&mut visuals.extreme_bg_color),
+        ImGuiCol::Button => f(&mut widgets.inactive.weak_bg_fill),
+        ImGuiCol::ButtonHovered => f(&mut widgets.hovered.weak_bg_fill),
+        ImGuiCol::ButtonActive => f(&mut widgets.active.weak_bg_fill),
+        ImGuiCol::TableRowBgAlt => f(&mut visuals.faint_bg_color),
+        ImGuiCol::TextSelectedBg => f(&mut visuals.selection.bg_fill),
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slots_with_a_field_are_read_back() {
+        let color = Color32::from_rgba_premultiplied(10, 20, 30, 40);
+        for col in ImGuiCol::ALL {
+            let before = crate::imgui::get_style();
+            let mut style = before.clone();
+            set(&mut style, col, color);
+            if col.has_field() {
+                assert_eq!(get(&style, col), color, "{col:?}");
+            } else {
+                assert!(style == before, "{col:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn names_match_the_slots() {
+        for col in ImGuiCol::ALL {
+            assert_eq!(ImGuiCol::from_name(col.name()), Some(col));
+            assert_eq!(
+                ImGuiCol::from_name(&format!("ImGuiCol_{}", col.name())),
+                Some(col)
+            );
+        }
+        assert_eq!(ImGuiCol::from_name("Nope"), None);
+    }
+}
@@ -0,0 +1,299 @@
+use egui::{epaint::Shadow, vec2, Color32, Style, Vec2};
+
+use crate::{
+    imgui_col::{self, imvec4, ImGuiCol},
+    style_stack::{get_var, set_var, StyleValue, StyleVar},
+};
+
+/// The variables of Dear ImGui's `ImGuiStyle`, so numbers from C++ projects can be used as is.
+///
+/// [`ImGuiStyle::apply`] writes the variables that egui has a field for, see
+/// [`StyleVar`] and [`crate::imgui_col`]. The other variables are kept so that a
+/// style converted from C++ can be converted back without losing them.
+/// ```no_run
+/// # use dear_egui::ImGuiStyle;
+/// # use egui::vec2;
+/// # fn f(ctx: &egui::Context) {
+/// let style = ImGuiStyle {
+///     frame_padding: vec2(6.0, 4.0),
+///     frame_rounding: 3.0,
+///     ..Default::default()
+/// };
+/// ctx.set_style(style.to_style());
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImGuiStyle {
+    pub alpha: f32,
+    pub disabled_alpha: f32,
+    pub window_padding: Vec2,
+    pub window_rounding: f32,
+    pub window_border_size: f32,
+    pub window_min_size: Vec2,
+    pub window_title_align: Vec2,
+    pub child_rounding: f32,
+    pub child_border_size: f32,
+    pub popup_rounding: f32,
+    pub popup_border_size: f32,
+    pub frame_padding: Vec2,
+    pub frame_rounding: f32,
+    pub frame_border_size: f32,
+    pub item_spacing: Vec2,
+    pub item_inner_spacing: Vec2,
+    pub cell_padding: Vec2,
+    pub touch_extra_padding: Vec2,
+    pub indent_spacing: f32,
+    pub columns_min_spacing: f32,
+    pub scrollbar_size: f32,
+    pub scrollbar_rounding: f32,
+    pub grab_min_size: f32,
+    pub grab_rounding: f32,
+    pub tab_rounding: f32,
+    pub tab_border_size: f32,
+    pub button_text_align: Vec2,
+    pub selectable_text_align: Vec2,
+    pub separator_text_border_size: f32,
+    pub display_window_padding: Vec2,
+    pub display_safe_area_padding: Vec2,
+    pub mouse_cursor_scale: f32,
+    pub anti_aliased_lines: bool,
+    pub anti_aliased_fill: bool,
+    pub curve_tessellation_tol: f32,
+    pub circle_tessellation_max_error: f32,
+    /// The colors indexed by [`ImGuiCol`].
+    pub colors: [Color32; 55],
+}
+
+/// The values of `ImGuiStyle::ImGuiStyle()` with the colors of `ImGui::StyleColorsDark()`.
+impl Default for ImGuiStyle {
+    fn default() -> Self {
+        Self {
+            alpha: 1.0,
+            disabled_alpha: 0.6,
+            window_padding: vec2(8.0, 8.0),
+            window_rounding: 0.0,
+            window_border_size: 1.0,
+            window_min_size: vec2(32.0, 32.0),
+            window_title_align: vec2(0.0, 0.5),
+            child_rounding: 0.0,
+            child_border_size: 1.0,
+            popup_rounding: 0.0,
+            popup_border_size: 1.0,
+            frame_padding: vec2(4.0, 3.0),
+            frame_rounding: 0.0,
+            frame_border_size: 0.0,
+            item_spacing: vec2(8.0, 4.0),
+            item_inner_spacing: vec2(4.0, 4.0),
+            cell_padding: vec2(4.0, 2.0),
+            touch_extra_padding: vec2(0.0, 0.0),
+            indent_spacing: 21.0,
+            columns_min_spacing: 6.0,
+            scrollbar_size: 14.0,
+            scrollbar_rounding: 9.0,
+            grab_min_size: 12.0,
+            grab_rounding: 0.0,
+            tab_rounding: 4.0,
+            tab_border_size: 0.0,
+            button_text_align: vec2(0.5, 0.5),
+            selectable_text_align: vec2(0.0, 0.0),
+            separator_text_border_size: 3.0,
+            display_window_padding: vec2(19.0, 19.0),
+            display_safe_area_padding: vec2(3.0, 3.0),
+            mouse_cursor_scale: 1.0,
+            anti_aliased_lines: true,
+            anti_aliased_fill: true,
+            curve_tessellation_tol: 1.25,
+            circle_tessellation_max_error: 0.3,
+            colors: colors_dark(),
+        }
+    }
+}
+
+impl ImGuiStyle {
+    /// Read the variables and colors that egui has a field for from `style`.
+    ///
+    /// The other variables keep their default values. Colors of slots that egui
+    /// draws with another slot's color are read from that slot.
+    pub fn from_style(style: &Style) -> Self {
+        let var = |var| get_var(style, var);
+        let mut imgui = Self {
+            window_padding: var(StyleVar::WindowPadding).vec2(),
+            window_rounding: var(StyleVar::WindowRounding).float(),
+            window_border_size: var(StyleVar::WindowBorderSize).float(),
+            popup_rounding: var(StyleVar::PopupRounding).float(),
+            frame_padding: var(StyleVar::FramePadding).vec2(),
+            frame_rounding: var(StyleVar::FrameRounding).float(),
+            frame_border_size: var(StyleVar::FrameBorderSize).float(),
+            item_spacing: var(StyleVar::ItemSpacing).vec2(),
+            item_inner_spacing: var(StyleVar::ItemInnerSpacing).vec2(),
+            indent_spacing: var(StyleVar::IndentSpacing).float(),
+            scrollbar_size: var(StyleVar::ScrollbarSize).float(),
+            grab_min_size: var(StyleVar::GrabMinSize).float(),
+            ..Self::default()
+        };
+        for col in ImGuiCol::ALL {
+            imgui.colors[col as usize] = imgui_col::get(style, col);
+        }
+        imgui
+    }
+
+    /// Get the color of a slot.
+    pub fn color(&self, col: ImGuiCol) -> Color32 {
+        self.colors[col as usize]
+    }
+
+    /// Set the color of a slot.
+    pub fn set_color(&mut self, col: ImGuiCol, color: Color32) {
+        self.colors[col as usize] = color;
+    }
+
+    /// Get a style with these variables and colors, based on [`crate::imgui::get_style`].
+    pub fn to_style(&self) -> Style {
+        let mut style = crate::imgui::get_style();
+        self.apply(&mut style);
+        style
+    }
+
+    /// Write the variables and colors that egui has a field for into `style`.
+    pub fn apply(&self, style: &mut Style) {
+        for (var, value) in self.vars() {
+            set_var(style, var, value);
+        }
+        for col in ImGuiCol::ALL {
+            if col.has_field() {
+                imgui_col::set(style, col, self.color(col));
+            }
+        }
+        // A transparent shadow is no shadow at all.
+        if self.color(ImGuiCol::BorderShadow) == Color32::TRANSPARENT {
+            style.visuals.window_shadow = Shadow::NONE;
+        }
+    }
+
+    /// The variables that map to a [`StyleVar`].
+    fn vars(&self) -> [(StyleVar, StyleValue); 12] {
+        [
+            (StyleVar::WindowPadding, self.window_padding.into()),
+            (StyleVar::WindowRounding, self.window_rounding.into()),
+            (StyleVar::WindowBorderSize, self.window_border_size.into()),
+            (StyleVar::PopupRounding, self.popup_rounding.into()),
+            (StyleVar::FramePadding, self.frame_padding.into()),
+            (StyleVar::FrameRounding, self.frame_rounding.into()),
+            (StyleVar::FrameBorderSize, self.frame_border_size.into()),
+            (StyleVar::ItemSpacing, self.item_spacing.into()),
+            (StyleVar::ItemInnerSpacing, self.item_inner_spacing.into()),
+            (StyleVar::IndentSpacing, self.indent_spacing.into()),
+            (StyleVar::ScrollbarSize, self.scrollbar_size.into()),
+            (StyleVar::GrabMinSize, self.grab_min_size.into()),
+        ]
+    }
+}
+
+/// The colors of `ImGui::StyleColorsDark()`.
+pub fn colors_dark() -> [Color32; 55] {
+    let lerp = |a: [f32; 4], b: [f32; 4], t: f32| -> [f32; 4] {
+        std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t)
+    };
+    let header = [0.26, 0.59, 0.98, 0.31];
+    let header_hovered = [0.26, 0.59, 0.98, 0.80];
+    let header_active = [0.26, 0.59, 0.98, 1.00];
+    let title_bg = [0.04, 0.04, 0.04, 1.00];
+    let title_bg_active = [0.16, 0.29, 0.48, 1.00];
+    let tab = lerp(header, title_bg_active, 0.80);
+    let tab_active = lerp(header_active, title_bg_active, 0.60);
+    let border = [0.43, 0.43, 0.50, 0.50];
+    let colors = [
+        [1.00, 1.00, 1.00, 1.00],
+        [0.50, 0.50, 0.50, 1.00],
+        [0.06, 0.06, 0.06, 0.94],
+        [0.00, 0.00, 0.00, 0.00],
+        [0.08, 0.08, 0.08, 0.94],
+        border,
+        [0.00, 0.00, 0.00, 0.00],
+        [0.16, 0.29, 0.48, 0.54],
+        [0.26, 0.59, 0.98, 0.40],
+        [0.26, 0.59, 0.98, 0.67],
+        title_bg,
+        title_bg_active,
+        [0.00, 0.00, 0.00, 0.51],
+        [0.14, 0.14, 0.14, 1.00],
+        [0.02, 0.02, 0.02, 0.53],
+        [0.31, 0.31, 0.31, 1.00],
+        [0.41, 0.41, 0.41, 1.00],
+        [0.51, 0.51, 0.51, 1.00],
+        [0.26, 0.59, 0.98, 1.00],
+        [0.24, 0.52, 0.88, 1.00],
+        [0.26, 0.59, 0.98, 1.00],
+        [0.26, 0.59, 0.98, 0.40],
+        [0.26, 0.59, 0.98, 1.00],
+        [0.06, 0.53, 0.98, 1.00],
+        header,
+        header_hovered,
+        header_active,
+        border,
+        [0.10, 0.40, 0.75, 0.78],
+        [0.10, 0.40, 0.75, 1.00],
+        [0.26, 0.59, 0.98, 0.20],
+        [0.26, 0.59, 0.98, 0.67],
+        [0.26, 0.59, 0.98, 0.95],
+        tab,
+        header_hovered,
+        tab_active,
+        lerp(tab, title_bg, 0.80),
+        lerp(tab_active, title_bg, 0.40),
+        [header_active[0], header_active[1], header_active[2], 0.70],
+        [0.20, 0.20, 0.20, 1.00],
+        [0.61, 0.61, 0.61, 1.00],
+        [1.00, 0.43, 0.35, 1.00],
+        [0.90, 0.70, 0.00, 1.00],
+        [1.00, 0.60, 0.00, 1.00],
+        [0.19, 0.19, 0.20, 1.00],
+        [0.31, 0.31, 0.35, 1.00],
+        [0.23, 0.23, 0.25, 1.00],
+        [0.00, 0.00, 0.00, 0.00],
+        [1.00, 1.00, 1.00, 0.06],
+        [0.26, 0.59, 0.98, 0.35],
+        [1.00, 1.00, 0.00, 0.90],
+        [0.26, 0.59, 0.98, 1.00],
+        [1.00, 1.00, 1.00, 0.70],
+        [0.80, 0.80, 0.80, 0.20],
+        [0.80, 0.80, 0.80, 0.35],
+    ];
+    colors.map(|[r, g, b, a]| imvec4(r, g, b, a))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mapped_variables_and_colors_round_trip() {
+        let mut imgui = ImGuiStyle {
+            window_padding: vec2(11.0, 12.0),
+            window_rounding: 13.0,
+            window_border_size: 2.0,
+            popup_rounding: 14.0,
+            frame_padding: vec2(15.0, 16.0),
+            frame_rounding: 17.0,
+            frame_border_size: 3.0,
+            item_spacing: vec2(18.0, 19.0),
+            // egui has a single icon spacing for both axes.
+            item_inner_spacing: vec2(20.0, 20.0),
+            indent_spacing: 21.0,
+            scrollbar_size: 22.0,
+            grab_min_size: 23.0,
+            ..Default::default()
+        };
+        for col in ImGuiCol::ALL {
+            let i = col as u8;
+            imgui.set_color(col, Color32::from_rgba_premultiplied(i, i + 1, i + 2, 200));
+        }
+        let round_trip = ImGuiStyle::from_style(&imgui.to_style());
+        assert_eq!(round_trip.vars(), imgui.vars());
+        for col in ImGuiCol::ALL {
+            if col.has_field() {
+                assert_eq!(round_trip.color(col), imgui.color(col), "{col:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,303 @@
+//! A small parser for JSON with comments and trailing commas, as written by VS Code.
+
+use crate::theme_file::ParseError;
+
+/// The deepest nesting of arrays and objects that is parsed, to not overflow the stack.
+const MAX_DEPTH: usize = 128;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    /// The members in the order they appear, each with the line of its key.
+    Object(Vec<(String, Value, usize)>),
+}
+
+impl Value {
+    /// Get the first member of an object named `key`.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(members) => members
+                .iter()
+                .find(|(name, _, _)| name == key)
+                .map(|(_, value, _)| value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn parse(text: &str) -> Result<Value, ParseError> {
+    let mut parser = Parser {
+        chars: text.chars().collect(),
+        pos: 0,
+        line: 1,
+        depth: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace()?;
+    if parser.pos < parser.chars.len() {
+        return Err(parser.error("Unexpected text after the value"));
+    }
+    Ok(value)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+    /// The number of arrays and objects the parser is in.
+    depth: usize,
+}
+
+impl Parser {
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.line,
+            message: message.into(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(self.error(format!("Expected `{expected}`, found `{c}`"))),
+            None => Err(self.error(format!("Expected `{expected}`, found the end"))),
+        }
+    }
+
+    /// Skip whitespace and `//` and `/* */` comments.
+    fn skip_whitespace(&mut self) -> Result<(), ParseError> {
+        loop {
+            match (self.peek(), self.chars.get(self.pos + 1)) {
+                (Some(c), _) if c.is_whitespace() => {
+                    self.next();
+                }
+                (Some('/'), Some('/')) => {
+                    while self.peek().is_some_and(|c| c != '\n') {
+                        self.next();
+                    }
+                }
+                (Some('/'), Some('*')) => {
+                    self.pos += 2;
+                    while !(self.peek() == Some('*') && self.chars.get(self.pos + 1) == Some(&'/'))
+                    {
+                        if self.next().is_none() {
+                            return Err(self.error("Unterminated comment"));
+                        }
+                    }
+                    self.pos += 2;
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, ParseError> {
+        self.skip_whitespace()?;
+        match self.peek() {
+            Some('{' | '[') if self.depth == MAX_DEPTH => {
+                Err(self.error(format!("Nested deeper than {MAX_DEPTH} levels")))
+            }
+            Some('{') => self.nested(Self::object),
+            Some('[') => self.nested(Self::array),
+            Some('"') => Ok(Value::String(self.string()?)),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            Some(c) if c.is_ascii_alphabetic() => {
+                let start = self.pos;
+                while self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+                    self.next();
+                }
+                let word: String = self.chars[start..self.pos].iter().collect();
+                match word.as_str() {
+                    "true" => Ok(Value::Bool(true)),
+                    "false" => Ok(Value::Bool(false)),
+                    "null" => Ok(Value::Null),
+                    _ => Err(self.error(format!("Unexpected `{word}`"))),
+                }
+            }
+            Some(c) => Err(self.error(format!("Unexpected `{c}`"))),
+            None => Err(self.error("Expected a value, found the end")),
+        }
+    }
+
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<Value, ParseError>,
+    ) -> Result<Value, ParseError> {
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn object(&mut self) -> Result<Value, ParseError> {
+        self.expect('{')?;
+        let mut members = Vec::new();
+        loop {
+            self.skip_whitespace()?;
+            if self.peek() == Some('}') {
+                self.next();
+                return Ok(Value::Object(members));
+            }
+            let line = self.line;
+            let key = self.string()?;
+            self.skip_whitespace()?;
+            self.expect(':')?;
+            let value = self.value()?;
+            members.push((key, value, line));
+            self.skip_whitespace()?;
+            match self.peek() {
+                Some(',') => {
+                    self.next();
+                }
+                Some('}') => {}
+                _ => return Err(self.error("Expected `,` or `}`")),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, ParseError> {
+        self.expect('[')?;
+        let mut values = Vec::new();
+        loop {
+            self.skip_whitespace()?;
+            if self.peek() == Some(']') {
+                self.next();
+                return Ok(Value::Array(values));
+            }
+            values.push(self.value()?);
+            self.skip_whitespace()?;
+            match self.peek() {
+                Some(',') => {
+                    self.next();
+                }
+                Some(']') => {}
+                _ => return Err(self.error("Expected `,` or `]`")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.expect('"')?;
+        let mut text = String::new();
+        loop {
+            match self.next() {
+                Some('"') => return Ok(text),
+                Some('\\') => {
+                    let escaped = match self.next() {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('u') => {
+                            let digits: String = (0..4).filter_map(|_| self.next()).collect();
+                            u32::from_str_radix(&digits, 16)
+                                .ok()
+                                .and_then(char::from_u32)
+                                .unwrap_or(char::REPLACEMENT_CHARACTER)
+                        }
+                        Some(c) => c,
+                        None => return Err(self.error("Unterminated string")),
+                    };
+                    text.push(escaped);
+                }
+                Some('\n') | None => return Err(self.error("Unterminated string")),
+                Some(c) => text.push(c),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, ParseError> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+        {
+            self.next();
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        text.parse()
+            .map(Value::Number)
+            .map_err(|_| self.error(format!("Expected a number, found `{text}`")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comments_and_trailing_commas() {
+        let text = r##"{
+            // A comment
+            "name": "Theme", /* another */
+            "colors": { "a": "#fff", "b": null, },
+            "list": [1, -2.5e1, true, false, "\u00e9\n",],
+        }"##;
+        let value = parse(text).unwrap();
+        assert_eq!(value.get("name").and_then(Value::as_str), Some("Theme"));
+        let Some(Value::Object(colors)) = value.get("colors") else {
+            panic!("colors is not an object");
+        };
+        assert_eq!(colors[1], ("b".to_owned(), Value::Null, 4));
+        assert_eq!(
+            value.get("list"),
+            Some(&Value::Array(vec![
+                Value::Number(1.0),
+                Value::Number(-25.0),
+                Value::Bool(true),
+                Value::Bool(false),
+                Value::String("\u{e9}\n".to_owned()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn errors_have_the_line() {
+        let errors = [
+            ("{\n\"a\": 1\n\"b\": 2\n}", 3),
+            ("{\n\"a\": tru\n}", 2),
+            ("[1, 2", 1),
+            ("\"open", 1),
+            ("/* open", 1),
+            ("{} {}", 1),
+            ("", 1),
+        ];
+        for (text, line) in errors {
+            assert_eq!(parse(text).unwrap_err().line, line, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn nesting_is_limited() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(parse(&nested(MAX_DEPTH)).is_ok());
+        assert!(parse(&nested(MAX_DEPTH + 1)).is_err());
+        assert!(parse(&"[".repeat(200_000)).is_err());
+        assert!(parse(&"{\"a\":".repeat(200_000)).is_err());
+    }
+}
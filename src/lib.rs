@@ -1,12 +1,60 @@
+pub mod auto;
+pub mod base16;
+pub mod codegen;
 pub mod colors;
+pub mod diff;
+pub mod editor;
+pub mod fields;
+pub mod font;
+#[cfg(feature = "icons")]
+pub mod icons;
 pub mod imgui;
+pub mod imgui_col;
+pub mod imgui_style;
+mod jsonc;
+pub mod pack;
+pub mod palette;
+#[cfg(feature = "eframe")]
+pub mod persist;
+pub mod picker;
+pub mod preview;
+pub mod registry;
+pub mod schemes;
+pub mod scope;
+pub mod style_stack;
+pub mod theme_file;
+pub mod typography;
+pub mod vscode;
+pub mod watch;
 
-use std::collections::BTreeMap;
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
 
+pub use auto::AutoTheme;
+pub use base16::Base16Scheme;
 pub use colors::*;
-use egui::{FontData, FontDefinitions, FontFamily, FontId, Style, TextStyle};
+pub use editor::StyleEditor;
+use egui::{Color32, Style};
+pub use font::*;
+pub use imgui_col::ImGuiCol;
+pub use imgui_style::ImGuiStyle;
+pub use palette::Palette;
+#[cfg(feature = "eframe")]
+pub use persist::ThemeState;
+pub use picker::{PickerLayout, ThemePicker};
+pub use preview::ThemePreview;
+pub use registry::ThemeProvider;
+pub use scope::{scope, ThemeFrame};
+pub use style_stack::{StyleStackExt, StyleVar};
+pub use theme_file::ThemeFile;
+pub use vscode::VsCodeTheme;
+pub use watch::ThemeWatcher;
 
-#[derive(Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy)]
 pub enum Theme {
     Imgui,
     Cadmium,
@@ -16,101 +64,513 @@ pub enum Theme {
     Iris,
     Violet,
     Raspberry,
-    Custom { hue: f32, brightness: f32 },
+    Cherry,
+    Photoshop,
+    Unreal,
+    MaterialFlat,
+    Enemymouse,
+    VisualStudio,
+    DeepDark,
+    Moonlight,
+    Nord,
+    Dracula,
+    SolarizedDark,
+    SolarizedLight,
+    GruvboxDark,
+    GruvboxLight,
+    CatppuccinMocha,
+    CatppuccinLatte,
+    /// A color theme in any hue.
+    Custom {
+        hue: f32,
+        brightness: f32,
+    },
+    /// A color theme whose active buttons and selection are filled with a color,
+    /// see [`Theme::from_accent`].
+    Accent(Color32),
+    /// A theme registered with [`registry::register_theme`].
+    Registered(&'static str),
 }
 
 impl Theme {
+    /// All built-in themes, not including [`Theme::Custom`] and [`Theme::Accent`].
+    pub const ALL: [Theme; 24] = [
+        Theme::Imgui,
+        Theme::Cadmium,
+        Theme::Acid,
+        Theme::Forest,
+        Theme::Sky,
+        Theme::Iris,
+        Theme::Violet,
+        Theme::Raspberry,
+        Theme::Cherry,
+        Theme::Photoshop,
+        Theme::Unreal,
+        Theme::MaterialFlat,
+        Theme::Enemymouse,
+        Theme::VisualStudio,
+        Theme::DeepDark,
+        Theme::Moonlight,
+        Theme::Nord,
+        Theme::Dracula,
+        Theme::SolarizedDark,
+        Theme::SolarizedLight,
+        Theme::GruvboxDark,
+        Theme::GruvboxLight,
+        Theme::CatppuccinMocha,
+        Theme::CatppuccinLatte,
+    ];
+
+    /// The display name of this theme.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Theme::Imgui => "Imgui",
+            Theme::Cadmium => "Cadmium",
+            Theme::Acid => "Acid",
+            Theme::Forest => "Forest",
+            Theme::Sky => "Sky",
+            Theme::Iris => "Iris",
+            Theme::Violet => "Violet",
+            Theme::Raspberry => "Raspberry",
+            Theme::Cherry => "Cherry",
+            Theme::Photoshop => "Photoshop",
+            Theme::Unreal => "Unreal",
+            Theme::MaterialFlat => "Material Flat",
+            Theme::Enemymouse => "Enemymouse",
+            Theme::VisualStudio => "Visual Studio",
+            Theme::DeepDark => "Deep Dark",
+            Theme::Moonlight => "Moonlight",
+            Theme::Nord => "Nord",
+            Theme::Dracula => "Dracula",
+            Theme::SolarizedDark => "Solarized Dark",
+            Theme::SolarizedLight => "Solarized Light",
+            Theme::GruvboxDark => "Gruvbox Dark",
+            Theme::GruvboxLight => "Gruvbox Light",
+            Theme::CatppuccinMocha => "Catppuccin Mocha",
+            Theme::CatppuccinLatte => "Catppuccin Latte",
+            Theme::Custom { .. } => "Custom",
+            Theme::Accent(_) => "Accent",
+            Theme::Registered(name) => name,
+        }
+    }
+
+    /// All built-in themes followed by all registered themes.
+    pub fn all() -> Vec<Theme> {
+        let mut themes = Theme::ALL.to_vec();
+        themes.extend(registry::registered_themes());
+        themes
+    }
+
+    /// The colors of this theme by their role.
+    ///
+    /// Only registered themes may not have a palette.
+    pub fn palette(&self) -> Option<Palette> {
+        match self {
+            Theme::Imgui => Some(imgui::get_palette()),
+            Theme::Registered(name) => registry::provider(name)?.palette(),
+            Theme::Accent(accent) => Some(colors::get_palette_from_accent(*accent)),
+            theme => match theme.hue_brightness() {
+                Some((hue, brightness)) => Some(colors::get_palette(hue, brightness)),
+                None => Some(
+                    theme
+                        .scheme_palette()
+                        .unwrap_or_else(|| Palette::from_style(&theme.get_base_style())),
+                ),
+            },
+        }
+    }
+
+    /// The font this theme is designed for, if it has one.
+    pub fn font(&self) -> Option<Font> {
+        match self {
+            Theme::Registered(name) => registry::provider(name)?.font(),
+            _ => None,
+        }
+    }
+
+    /// Get the complete style of this theme using the typography of its font,
+    /// or of the default font if it has none, see [`Theme::font`].
+    ///
+    /// Use [`Theme::get_style_with_font`] to get the style for a different font.
     pub fn get_style(&self) -> Style {
+        self.get_style_with_font(&self.font_setup())
+    }
+
+    /// The font this theme is designed for, or the default font.
+    pub fn font_setup(&self) -> FontSetup {
+        self.font().map(FontSetup::new).unwrap_or_default()
+    }
+
+    /// Get the complete style of this theme using the typography of `font`.
+    pub fn get_style_with_font(&self, font: &FontSetup) -> Style {
+        let mut style = self.get_base_style();
+        font.apply_text_styles(&mut style);
+        style
+    }
+
+    /// Get the colors, spacing and shapes of this theme.
+    ///
+    /// The text styles of the base style are egui's defaults and are meant to be
+    /// replaced by the typography of a font.
+    ///
+    /// A registered theme that is no longer registered falls back to [`Theme::Imgui`].
+    pub fn get_base_style(&self) -> Style {
         match self {
             Theme::Imgui => imgui::get_style(),
-            Theme::Cadmium => colors::get_style(0.0, 0.80),
-            Theme::Acid => colors::get_style(70.0, 0.60),
-            Theme::Forest => colors::get_style(160.0, 0.70),
-            Theme::Sky => colors::get_style(212.0, 1.00),
-            Theme::Iris => colors::get_style(240.0, 1.30),
-            Theme::Violet => colors::get_style(290.0, 0.75),
-            Theme::Raspberry => colors::get_style(310.0, 0.70),
-            Theme::Custom { hue, brightness } => colors::get_style(*hue, *brightness),
+            Theme::Registered(name) => registry::provider(name)
+                .map(|provider| provider.get_base_style())
+                .unwrap_or_else(imgui::get_style),
+            Theme::Cherry => pack::cherry::get_style(),
+            Theme::Photoshop => pack::photoshop::get_style(),
+            Theme::Unreal => pack::unreal::get_style(),
+            Theme::MaterialFlat => pack::material_flat::get_style(),
+            Theme::Enemymouse => pack::enemymouse::get_style(),
+            Theme::VisualStudio => pack::visual_studio::get_style(),
+            Theme::DeepDark => pack::deep_dark::get_style(),
+            Theme::Moonlight => pack::moonlight::get_style(),
+            Theme::Accent(accent) => {
+                colors::get_style_from_palette(&colors::get_palette_from_accent(*accent))
+            }
+            theme => match theme.scheme_palette() {
+                Some(palette) => palette.get_style(),
+                None => {
+                    let (hue, brightness) = theme.hue_brightness().unwrap();
+                    colors::get_style(hue, brightness)
+                }
+            },
+        }
+    }
+
+    /// The palette of the editor color schemes.
+    fn scheme_palette(&self) -> Option<Palette> {
+        match self {
+            Theme::Nord => Some(schemes::nord()),
+            Theme::Dracula => Some(schemes::dracula()),
+            Theme::SolarizedDark => Some(schemes::solarized_dark()),
+            Theme::SolarizedLight => Some(schemes::solarized_light()),
+            Theme::GruvboxDark => Some(schemes::gruvbox_dark()),
+            Theme::GruvboxLight => Some(schemes::gruvbox_light()),
+            Theme::CatppuccinMocha => Some(schemes::catppuccin_mocha()),
+            Theme::CatppuccinLatte => Some(schemes::catppuccin_latte()),
+            _ => None,
+        }
+    }
+
+    /// The position of the variant in the declaration of [`Theme`].
+    fn rank(&self) -> usize {
+        match self {
+            Theme::Custom { .. } => Theme::ALL.len(),
+            Theme::Accent(_) => Theme::ALL.len() + 1,
+            Theme::Registered(_) => Theme::ALL.len() + 2,
+            theme => Theme::ALL.iter().position(|other| other == theme).unwrap(),
+        }
+    }
+
+    /// The hue and brightness of the color themes.
+    fn hue_brightness(&self) -> Option<(f32, f32)> {
+        match self {
+            Theme::Cadmium => Some((0.0, 0.80)),
+            Theme::Acid => Some((70.0, 0.60)),
+            Theme::Forest => Some((160.0, 0.70)),
+            Theme::Sky => Some((212.0, 1.00)),
+            Theme::Iris => Some((240.0, 1.30)),
+            Theme::Violet => Some((290.0, 0.75)),
+            Theme::Raspberry => Some((310.0, 0.70)),
+            Theme::Custom { hue, brightness } => Some((*hue, *brightness)),
+            _ => None,
+        }
+    }
+
+    /// A color theme whose active buttons and selection are filled with `accent`,
+    /// which is expected to be opaque.
+    ///
+    /// The hovered and inactive fills are derived from it like in the other color themes,
+    /// see [`colors::get_palette_from_accent`]. Colors that are close to gray give a gray
+    /// theme. The other fills are darker than the accent, so a near-white accent gives light
+    /// gray fills and a near-black accent gives fills that hardly stand out from the window
+    /// background.
+    pub fn from_accent(accent: Color32) -> Theme {
+        let [r, g, b, _] = accent.to_array();
+        Theme::Accent(Color32::from_rgb(r, g, b))
+    }
+
+    /// An accent theme built from an opaque accent color like `#ff8800` or `#f80`,
+    /// see [`Theme::from_accent`].
+    pub fn from_hex(hex: &str) -> Result<Theme, ParseThemeError> {
+        hex.strip_prefix('#')
+            .filter(|digits| matches!(digits.len(), 3 | 6))
+            .and_then(|_| fields::parse_color(hex).ok())
+            .map(Theme::from_accent)
+            .ok_or_else(|| ParseThemeError(hex.to_owned()))
+    }
+}
+
+pub fn set_theme(ctx: &egui::Context, theme: Theme, font: impl Into<FontSetup>) {
+    let font = font.into();
+    ctx.set_fonts(font.font_definitions());
+    ctx.set_style(theme.get_style_with_font(&font));
+}
+
+/// Set a theme with the font it is designed for, see [`Theme::font_setup`].
+pub fn set_theme_with_its_font(ctx: &egui::Context, theme: Theme) {
+    set_theme(ctx, theme, theme.font_setup());
+}
+
+// The hue and brightness of a custom theme are compared by their bits
+// so that every theme is equal to itself and can be used as a key. The names of
+// registered themes are compared ignoring ASCII case, like the registry does.
+impl PartialEq for Theme {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Theme::Custom { hue, brightness },
+                Theme::Custom {
+                    hue: other_hue,
+                    brightness: other_brightness,
+                },
+            ) => {
+                hue.to_bits() == other_hue.to_bits()
+                    && brightness.to_bits() == other_brightness.to_bits()
+            }
+            (Theme::Accent(accent), Theme::Accent(other_accent)) => accent == other_accent,
+            (Theme::Registered(name), Theme::Registered(other_name)) => {
+                name.eq_ignore_ascii_case(other_name)
+            }
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for Theme {}
+
+// Themes are ordered like their variants. Custom themes are ordered by `f32::total_cmp`,
+// which agrees with comparing the bits.
+impl Ord for Theme {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (
+                Theme::Custom { hue, brightness },
+                Theme::Custom {
+                    hue: other_hue,
+                    brightness: other_brightness,
+                },
+            ) => hue
+                .total_cmp(other_hue)
+                .then(brightness.total_cmp(other_brightness)),
+            (Theme::Accent(accent), Theme::Accent(other_accent)) => {
+                accent.to_array().cmp(&other_accent.to_array())
+            }
+            (Theme::Registered(name), Theme::Registered(other_name)) => {
+                let folded = |name: &'static str| name.bytes().map(|b| b.to_ascii_lowercase());
+                folded(name).cmp(folded(other_name))
+            }
+            _ => self.rank().cmp(&other.rank()),
         }
     }
 }
 
-#[derive(Clone, Copy)]
-pub enum Font {
-    OpenSans,
-    ProggyClean,
+impl PartialOrd for Theme {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
-impl Font {
-    /// Get the font definition and text styles for this font.
-    pub fn get_style(&self) -> (FontDefinitions, BTreeMap<TextStyle, FontId>) {
+impl Hash for Theme {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
         match self {
-            Font::ProggyClean => {
-                let mut fonts = FontDefinitions::default();
-                //Install my own font (maybe supporting non-latin characters):
-                fonts.font_data.insert(
-                    "ProggyClean".to_owned(),
-                    FontData::from_static(include_bytes!("../font/ProggyClean.ttf")),
-                );
-                // Put my font first (highest priority):
-                fonts
-                    .families
-                    .get_mut(&FontFamily::Proportional)
-                    .unwrap()
-                    .insert(0, "ProggyClean".to_owned());
-                fonts
-                    .families
-                    .get_mut(&FontFamily::Monospace)
-                    .unwrap()
-                    .insert(0, "ProggyClean".to_owned());
-
-                use FontFamily::{Monospace, Proportional};
-                return (
-                    fonts,
-                    [
-                        (TextStyle::Small, FontId::new(16.0, Proportional)),
-                        (TextStyle::Body, FontId::new(16.0, Proportional)),
-                        (TextStyle::Monospace, FontId::new(16.0, Monospace)),
-                        (TextStyle::Button, FontId::new(16.0, Proportional)),
-                        (TextStyle::Heading, FontId::new(32.0, Proportional)),
-                    ]
-                    .into(),
-                );
+            Theme::Custom { hue, brightness } => {
+                hue.to_bits().hash(state);
+                brightness.to_bits().hash(state);
             }
-            Font::OpenSans => {
-                let mut fonts = FontDefinitions::default();
-                //Install my own font (maybe supporting non-latin characters):
-                fonts.font_data.insert(
-                    "OpenSans".to_owned(),
-                    FontData::from_static(include_bytes!("../font/OpenSans-Regular.ttf")),
-                );
-                // Put my font first (highest priority):
-                fonts
-                    .families
-                    .get_mut(&FontFamily::Proportional)
-                    .unwrap()
-                    .insert(0, "OpenSans".to_owned());
-
-                use FontFamily::{Monospace, Proportional};
-                return (
-                    fonts,
-                    [
-                        (TextStyle::Small, FontId::new(10.0, Proportional)),
-                        (TextStyle::Body, FontId::new(12.0, Proportional)),
-                        (TextStyle::Monospace, FontId::new(12.0, Monospace)),
-                        (TextStyle::Button, FontId::new(12.0, Proportional)),
-                        (TextStyle::Heading, FontId::new(16.0, Proportional)),
-                    ]
-                    .into(),
-                );
+            Theme::Accent(accent) => accent.hash(state),
+            Theme::Registered(name) => {
+                for b in name.bytes() {
+                    b.to_ascii_lowercase().hash(state);
+                }
             }
+            _ => (),
         }
     }
 }
 
-pub fn set_theme(ctx: &egui::Context, theme: Theme, font: Font) {
-    let (font, text_styles) = font.get_style();
-    ctx.set_fonts(font);
-    ctx.set_style(theme.get_style());
-    ctx.style_mut(|style| style.text_styles = text_styles);
+/// Formats the theme so that it can be parsed again with [`Theme::from_str`].
+///
+/// Built-in and registered themes are written as their name,
+/// custom themes as `custom:hue,brightness` and accent themes as their color like `#ff8800`.
+impl Display for Theme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Theme::Custom { hue, brightness } => write!(f, "custom:{hue},{brightness}"),
+            Theme::Accent(accent) => {
+                let [r, g, b, _] = accent.to_array();
+                write!(f, "#{r:02x}{g:02x}{b:02x}")
+            }
+            theme => write!(f, "{}", theme.name()),
+        }
+    }
+}
+
+/// The error returned when parsing a theme fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseThemeError(String);
+
+impl Display for ParseThemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown theme `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseThemeError {}
+
+/// Parses the name of a built-in theme ignoring case and spaces, so `SolarizedDark` is
+/// read as `Solarized Dark`, the name of a registered theme ignoring case, a custom theme
+/// in the form `custom:hue,brightness` or an accent color like `#ff8800`.
+impl FromStr for Theme {
+    type Err = ParseThemeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let without_spaces = |name: &str| name.replace(' ', "").to_ascii_lowercase();
+        if let Some(theme) = Theme::ALL
+            .into_iter()
+            .find(|theme| without_spaces(theme.name()) == without_spaces(s))
+        {
+            return Ok(theme);
+        }
+        if let Some(theme) = registry::find_theme(s) {
+            return Ok(theme);
+        }
+        if s.starts_with('#') {
+            return Theme::from_hex(s);
+        }
+        let custom = s
+            .split_once(':')
+            .filter(|(prefix, _)| prefix.trim().eq_ignore_ascii_case("custom"))
+            .and_then(|(_, values)| {
+                let mut values = values.split(',').map(|value| {
+                    value
+                        .trim()
+                        .parse::<f32>()
+                        .ok()
+                        .filter(|value| value.is_finite())
+                });
+                let theme = Theme::Custom {
+                    hue: values.next()??,
+                    brightness: values.next()??,
+                };
+                values.next().is_none().then_some(theme)
+            });
+        custom.ok_or_else(|| ParseThemeError(s.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn themes_round_trip_through_strings() {
+        let custom = [
+            Theme::Custom {
+                hue: 212.5,
+                brightness: 0.8,
+            },
+            Theme::from_accent(Color32::from_rgb(255, 136, 0)),
+        ];
+        for theme in Theme::ALL.into_iter().chain(custom) {
+            assert_eq!(theme.to_string().parse::<Theme>(), Ok(theme));
+        }
+        assert_eq!(
+            "custom:10,0.5".parse::<Theme>(),
+            Ok(Theme::Custom {
+                hue: 10.0,
+                brightness: 0.5,
+            })
+        );
+        assert_eq!(" cherry ".parse::<Theme>(), Ok(Theme::Cherry));
+        assert_eq!("SolarizedDark".parse::<Theme>(), Ok(Theme::SolarizedDark));
+        assert_eq!("material flat".parse::<Theme>(), Ok(Theme::MaterialFlat));
+        assert_eq!("VisualStudio".parse::<Theme>(), Ok(Theme::VisualStudio));
+    }
+
+    #[test]
+    fn malformed_themes_are_rejected() {
+        for text in [
+            "",
+            "nope",
+            "custom:",
+            "custom:1",
+            "custom:a,b",
+            "custom:1,2,3",
+            "custom:nan,1",
+            "custom:inf,1",
+            "custom:1,nan",
+            "custom:1,-inf",
+            "other:1,2",
+            "#ff88",
+            "#ff880080",
+            "premultiplied #80440080",
+            "#ff88zz",
+        ] {
+            assert!(text.parse::<Theme>().is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn accent_themes_keep_the_accent() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(17) {
+                for b in (0..=255).step_by(51) {
+                    let accent = Color32::from_rgb(r, g, b);
+                    let palette = Theme::from_accent(accent).palette().unwrap();
+                    assert_eq!(palette.button_active, accent);
+                    assert_eq!(palette.selection, accent);
+                }
+            }
+        }
+        assert_eq!(
+            "#f80".parse::<Theme>(),
+            Ok(Theme::from_accent(Color32::from_rgb(255, 136, 0)))
+        );
+        assert_eq!(
+            Theme::from_hex("#3c78d8"),
+            Ok(Theme::from_accent(Color32::from_rgb(60, 120, 216)))
+        );
+    }
+
+    #[test]
+    fn non_finite_custom_themes_do_not_panic() {
+        for value in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            Theme::Custom {
+                hue: value,
+                brightness: value,
+            }
+            .get_style();
+        }
+    }
+
+    #[test]
+    fn ordering_agrees_with_equality() {
+        let zero = Theme::Custom {
+            hue: 0.0,
+            brightness: 1.0,
+        };
+        let negative_zero = Theme::Custom {
+            hue: -0.0,
+            brightness: 1.0,
+        };
+        let nan = Theme::Custom {
+            hue: f32::NAN,
+            brightness: 1.0,
+        };
+        assert_ne!(zero, negative_zero);
+        assert_ne!(zero.cmp(&negative_zero), Ordering::Equal);
+        assert_eq!(nan, nan);
+        assert_eq!(nan.partial_cmp(&nan), Some(Ordering::Equal));
+        assert!(Theme::Imgui < Theme::Sky);
+        assert!(Theme::CatppuccinLatte < zero);
+        assert!(zero < Theme::from_accent(Color32::BLACK));
+        assert!(Theme::from_accent(Color32::WHITE) < Theme::Registered("a"));
+    }
 }
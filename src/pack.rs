@@ -0,0 +1,162 @@
+//! Community styles of Dear ImGui.
+//!
+//! Each style is written as an [`ImGuiStyle`] with the variables and colors of its
+//! C++ source and converted with [`ImGuiStyle::to_style`], so the parts that ImGui
+//! has no variable for are those of [`crate::imgui::get_style`].
+
+pub mod cherry;
+pub mod deep_dark;
+pub mod enemymouse;
+pub mod material_flat;
+pub mod moonlight;
+pub mod photoshop;
+pub mod unreal;
+pub mod visual_studio;
+
+use crate::{imgui_col::imvec4, ImGuiCol, ImGuiStyle};
+
+/// Set colors given as the float components of an `ImVec4`.
+fn set_colors(style: &mut ImGuiStyle, colors: &[(ImGuiCol, [f32; 4])]) {
+    for &(col, [r, g, b, a]) in colors {
+        style.set_color(col, imvec4(r, g, b, a));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::Color32;
+
+    use super::*;
+
+    #[test]
+    fn windows_have_no_invisible_shadow() {
+        let styles = [
+            cherry::get_style,
+            deep_dark::get_style,
+            enemymouse::get_style,
+            material_flat::get_style,
+            moonlight::get_style,
+            photoshop::get_style,
+            unreal::get_style,
+            visual_studio::get_style,
+        ];
+        for get_style in styles {
+            let shadow = get_style().visuals.window_shadow;
+            assert!(shadow.extrusion == 0.0 || shadow.color != Color32::TRANSPARENT);
+        }
+    }
+
+    type GetStyle = fn() -> egui::Style;
+
+    /// Text, window, frame, button, hovered button and selection colors.
+    fn key_colors(style: &egui::Style) -> [String; 6] {
+        let visuals = &style.visuals;
+        let widgets = &visuals.widgets;
+        [
+            widgets.inactive.fg_stroke.color,
+            visuals.window_fill,
+            widgets.inactive.bg_fill,
+            widgets.inactive.weak_bg_fill,
+            widgets.hovered.weak_bg_fill,
+            visuals.selection.bg_fill,
+        ]
+        .map(|color| color.to_hex())
+    }
+
+    #[test]
+    fn packs_keep_their_key_colors() {
+        let expected: [(GetStyle, [&str; 6]); 8] = [
+            (
+                cherry::get_style,
+                [
+                    "#dbede3c7",
+                    "#21242bff",
+                    "#333845ff",
+                    "#77c4d424",
+                    "#74324ddb",
+                    "#75324d6e",
+                ],
+            ),
+            (
+                deep_dark::get_style,
+                [
+                    "#ffffffff",
+                    "#1a1a1aff",
+                    "#0d0d0d8a",
+                    "#0d0d0d8a",
+                    "#3030308a",
+                    "#33383bff",
+                ],
+            ),
+            (
+                enemymouse::get_style,
+                [
+                    "#00ffffff",
+                    "#000000ff",
+                    "#71cccc2e",
+                    "#00a6a675",
+                    "#02fefe6e",
+                    "#00ffff38",
+                ],
+            ),
+            (
+                material_flat::get_style,
+                [
+                    "#d4d8e0ff",
+                    "#2c313cff",
+                    "#1b1d23ff",
+                    "#272d3680",
+                    "#272c36ff",
+                    "#343b48ff",
+                ],
+            ),
+            (
+                moonlight::get_style,
+                [
+                    "#ffffffff",
+                    "#14161aff",
+                    "#1d2028ff",
+                    "#1e2226ff",
+                    "#2e3032ff",
+                    "#efefefff",
+                ],
+            ),
+            (
+                photoshop::get_style,
+                [
+                    "#ffffffff",
+                    "#2e2e2eff",
+                    "#292929ff",
+                    "#00000000",
+                    "#fefefe28",
+                    "#fefefe28",
+                ],
+            ),
+            (
+                unreal::get_style,
+                [
+                    "#ffffffff",
+                    "#0f0f0ff0",
+                    "#3336388a",
+                    "#70707066",
+                    "#75787aff",
+                    "#dedede59",
+                ],
+            ),
+            (
+                visual_studio::get_style,
+                [
+                    "#ffffffff",
+                    "#252526ff",
+                    "#333337ff",
+                    "#333337ff",
+                    "#1d97ecff",
+                    "#0077c8ff",
+                ],
+            ),
+        ];
+        for (get_style, colors) in expected {
+            assert_eq!(key_colors(&get_style()), colors);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! The Cherry style by r-lyeh.
+
+use egui::{vec2, Style};
+
+use super::set_colors;
+use crate::{ImGuiCol::*, ImGuiStyle};
+
+/// The variables and colors of the C++ source.
+pub fn imgui_style() -> ImGuiStyle {
+    let hi = |v: f32| [0.502, 0.075, 0.256, v];
+    let med = |v: f32| [0.455, 0.198, 0.301, v];
+    let low = |v: f32| [0.232, 0.201, 0.271, v];
+    let bg = |v: f32| [0.200, 0.220, 0.270, v];
+    let text = |v: f32| [0.860, 0.930, 0.890, v];
+    let mut style = ImGuiStyle {
+        window_padding: vec2(6.0, 4.0),
+        window_rounding: 0.0,
+        frame_padding: vec2(5.0, 2.0),
+        frame_rounding: 3.0,
+        item_spacing: vec2(7.0, 1.0),
+        item_inner_spacing: vec2(1.0, 1.0),
+        touch_extra_padding: vec2(0.0, 0.0),
+        indent_spacing: 6.0,
+        scrollbar_size: 12.0,
+        scrollbar_rounding: 16.0,
+        grab_min_size: 20.0,
+        grab_rounding: 2.0,
+        window_title_align: vec2(0.5, 0.5),
+        frame_border_size: 0.0,
+        window_border_size: 1.0,
+        ..Default::default()
+    };
+    set_colors(
+        &mut style,
+        &[
+            (Text, text(0.78)),
+            (TextDisabled, text(0.28)),
+            (WindowBg, [0.13, 0.14, 0.17, 1.0]),
+            (ChildBg, bg(0.58)),
+            (PopupBg, bg(0.9)),
+            (Border, [0.539, 0.479, 0.255, 0.162]),
+            (BorderShadow, [0.0, 0.0, 0.0, 0.0]),
+            (FrameBg, bg(1.0)),
+            (FrameBgHovered, med(0.78)),
+            (FrameBgActive, med(1.0)),
+            (TitleBg, low(1.0)),
+            (TitleBgActive, hi(1.0)),
+            (TitleBgCollapsed, bg(0.75)),
+            (MenuBarBg, bg(0.47)),
+            (ScrollbarBg, bg(1.0)),
+            (ScrollbarGrab, [0.09, 0.15, 0.16, 1.0]),
+            (ScrollbarGrabHovered, med(0.78)),
+            (ScrollbarGrabActive, med(1.0)),
+            (CheckMark, [0.71, 0.22, 0.27, 1.0]),
+            (SliderGrab, [0.47, 0.77, 0.83, 0.14]),
+            (SliderGrabActive, [0.71, 0.22, 0.27, 1.0]),
+            (Button, [0.47, 0.77, 0.83, 0.14]),
+            (ButtonHovered, med(0.86)),
+            (ButtonActive, med(1.0)),
+            (Header, med(0.76)),
+            (HeaderHovered, med(0.86)),
+            (HeaderActive, hi(1.0)),
+            (Separator, [0.14, 0.16, 0.19, 1.0]),
+            (SeparatorHovered, med(0.78)),
+            (SeparatorActive, med(1.0)),
+            (ResizeGrip, [0.47, 0.77, 0.83, 0.04]),
+            (ResizeGripHovered, med(0.78)),
+            (ResizeGripActive, med(1.0)),
+            (PlotLines, text(0.63)),
+            (PlotLinesHovered, med(1.0)),
+            (PlotHistogram, text(0.63)),
+            (PlotHistogramHovered, med(1.0)),
+            (TextSelectedBg, med(0.43)),
+            (ModalWindowDimBg, bg(0.73)),
+        ],
+    );
+    style
+}
+
+pub fn get_style() -> Style {
+    imgui_style().to_style()
+}
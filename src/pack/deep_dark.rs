@@ -0,0 +1,100 @@
+//! The Deep Dark style by janekb04.
+
+use egui::{vec2, Style};
+
+use super::set_colors;
+use crate::{ImGuiCol::*, ImGuiStyle};
+
+/// The variables and colors of the C++ source.
+pub fn imgui_style() -> ImGuiStyle {
+    let mut style = ImGuiStyle {
+        window_padding: vec2(8.0, 8.0),
+        frame_padding: vec2(5.0, 2.0),
+        cell_padding: vec2(6.0, 6.0),
+        item_spacing: vec2(6.0, 6.0),
+        item_inner_spacing: vec2(6.0, 6.0),
+        touch_extra_padding: vec2(0.0, 0.0),
+        indent_spacing: 25.0,
+        scrollbar_size: 15.0,
+        grab_min_size: 10.0,
+        window_border_size: 1.0,
+        child_border_size: 1.0,
+        popup_border_size: 1.0,
+        frame_border_size: 1.0,
+        tab_border_size: 1.0,
+        window_rounding: 7.0,
+        child_rounding: 4.0,
+        frame_rounding: 3.0,
+        popup_rounding: 4.0,
+        scrollbar_rounding: 9.0,
+        grab_rounding: 3.0,
+        tab_rounding: 4.0,
+        ..Default::default()
+    };
+    let red = |a: f32| [1.0, 0.0, 0.0, a];
+    set_colors(
+        &mut style,
+        &[
+            (Text, [1.0, 1.0, 1.0, 1.0]),
+            (TextDisabled, [0.5, 0.5, 0.5, 1.0]),
+            (WindowBg, [0.1, 0.1, 0.1, 1.0]),
+            (ChildBg, [0.0, 0.0, 0.0, 0.0]),
+            (PopupBg, [0.19, 0.19, 0.19, 0.92]),
+            (Border, [0.19, 0.19, 0.19, 0.29]),
+            (BorderShadow, [0.0, 0.0, 0.0, 0.24]),
+            (FrameBg, [0.05, 0.05, 0.05, 0.54]),
+            (FrameBgHovered, [0.19, 0.19, 0.19, 0.54]),
+            (FrameBgActive, [0.2, 0.22, 0.23, 1.0]),
+            (TitleBg, [0.0, 0.0, 0.0, 1.0]),
+            (TitleBgActive, [0.06, 0.06, 0.06, 1.0]),
+            (TitleBgCollapsed, [0.0, 0.0, 0.0, 1.0]),
+            (MenuBarBg, [0.14, 0.14, 0.14, 1.0]),
+            (ScrollbarBg, [0.05, 0.05, 0.05, 0.54]),
+            (ScrollbarGrab, [0.34, 0.34, 0.34, 0.54]),
+            (ScrollbarGrabHovered, [0.4, 0.4, 0.4, 0.54]),
+            (ScrollbarGrabActive, [0.56, 0.56, 0.56, 0.54]),
+            (CheckMark, [0.33, 0.67, 0.86, 1.0]),
+            (SliderGrab, [0.34, 0.34, 0.34, 0.54]),
+            (SliderGrabActive, [0.56, 0.56, 0.56, 0.54]),
+            (Button, [0.05, 0.05, 0.05, 0.54]),
+            (ButtonHovered, [0.19, 0.19, 0.19, 0.54]),
+            (ButtonActive, [0.2, 0.22, 0.23, 1.0]),
+            (Header, [0.0, 0.0, 0.0, 0.52]),
+            (HeaderHovered, [0.0, 0.0, 0.0, 0.36]),
+            (HeaderActive, [0.2, 0.22, 0.23, 0.33]),
+            (Separator, [0.28, 0.28, 0.28, 0.29]),
+            (SeparatorHovered, [0.44, 0.44, 0.44, 0.29]),
+            (SeparatorActive, [0.4, 0.44, 0.47, 1.0]),
+            (ResizeGrip, [0.28, 0.28, 0.28, 0.29]),
+            (ResizeGripHovered, [0.44, 0.44, 0.44, 0.29]),
+            (ResizeGripActive, [0.4, 0.44, 0.47, 1.0]),
+            (Tab, [0.0, 0.0, 0.0, 0.52]),
+            (TabHovered, [0.14, 0.14, 0.14, 1.0]),
+            (TabActive, [0.2, 0.2, 0.2, 0.36]),
+            (TabUnfocused, [0.0, 0.0, 0.0, 0.52]),
+            (TabUnfocusedActive, [0.14, 0.14, 0.14, 1.0]),
+            (DockingPreview, [0.33, 0.67, 0.86, 1.0]),
+            (DockingEmptyBg, red(1.0)),
+            (PlotLines, red(1.0)),
+            (PlotLinesHovered, red(1.0)),
+            (PlotHistogram, red(1.0)),
+            (PlotHistogramHovered, red(1.0)),
+            (TableHeaderBg, [0.0, 0.0, 0.0, 0.52]),
+            (TableBorderStrong, [0.0, 0.0, 0.0, 0.52]),
+            (TableBorderLight, [0.28, 0.28, 0.28, 0.29]),
+            (TableRowBg, [0.0, 0.0, 0.0, 0.0]),
+            (TableRowBgAlt, [1.0, 1.0, 1.0, 0.06]),
+            (TextSelectedBg, [0.2, 0.22, 0.23, 1.0]),
+            (DragDropTarget, [0.33, 0.67, 0.86, 1.0]),
+            (NavHighlight, red(1.0)),
+            (NavWindowingHighlight, red(0.7)),
+            (NavWindowingDimBg, red(0.2)),
+            (ModalWindowDimBg, red(0.35)),
+        ],
+    );
+    style
+}
+
+pub fn get_style() -> Style {
+    imgui_style().to_style()
+}
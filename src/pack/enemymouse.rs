@@ -0,0 +1,70 @@
+//! The Enemymouse style by enemymouse, cyan on black.
+
+use egui::{vec2, Style};
+
+use super::set_colors;
+use crate::{ImGuiCol::*, ImGuiStyle};
+
+/// The variables and colors of the C++ source.
+pub fn imgui_style() -> ImGuiStyle {
+    let mut style = ImGuiStyle {
+        window_rounding: 3.0,
+        grab_rounding: 1.0,
+        grab_min_size: 20.0,
+        frame_rounding: 3.0,
+        indent_spacing: 6.0,
+        item_inner_spacing: vec2(2.0, 4.0),
+        item_spacing: vec2(6.0, 2.0),
+        ..Default::default()
+    };
+    let cyan = |a: f32| [0.0, 1.0, 1.0, a];
+    set_colors(
+        &mut style,
+        &[
+            (Text, cyan(1.0)),
+            (TextDisabled, [0.0, 0.4, 0.41, 1.0]),
+            (WindowBg, [0.0, 0.0, 0.0, 1.0]),
+            (ChildBg, [0.0, 0.0, 0.0, 0.0]),
+            (PopupBg, [0.16, 0.24, 0.22, 0.6]),
+            (Border, cyan(0.65)),
+            (BorderShadow, [0.0, 0.0, 0.0, 0.0]),
+            (FrameBg, [0.44, 0.8, 0.8, 0.18]),
+            (FrameBgHovered, [0.44, 0.8, 0.8, 0.27]),
+            (FrameBgActive, [0.44, 0.81, 0.86, 0.66]),
+            (TitleBg, [0.14, 0.18, 0.21, 0.73]),
+            (TitleBgCollapsed, [0.0, 0.0, 0.0, 0.54]),
+            (TitleBgActive, cyan(0.27)),
+            (MenuBarBg, [0.0, 0.0, 0.0, 0.2]),
+            (ScrollbarBg, [0.22, 0.29, 0.3, 0.71]),
+            (ScrollbarGrab, cyan(0.44)),
+            (ScrollbarGrabHovered, cyan(0.74)),
+            (ScrollbarGrabActive, cyan(1.0)),
+            (CheckMark, cyan(0.68)),
+            (SliderGrab, cyan(0.36)),
+            (SliderGrabActive, cyan(0.76)),
+            (Button, [0.0, 0.65, 0.65, 0.46]),
+            (ButtonHovered, [0.01, 1.0, 1.0, 0.43]),
+            (ButtonActive, cyan(0.62)),
+            (Header, cyan(0.33)),
+            (HeaderHovered, cyan(0.42)),
+            (HeaderActive, cyan(0.54)),
+            (Separator, [0.0, 0.5, 0.5, 0.33]),
+            (SeparatorHovered, [0.0, 0.5, 0.5, 0.47]),
+            (SeparatorActive, [0.0, 0.7, 0.7, 1.0]),
+            (ResizeGrip, cyan(0.54)),
+            (ResizeGripHovered, cyan(0.74)),
+            (ResizeGripActive, cyan(1.0)),
+            (PlotLines, cyan(1.0)),
+            (PlotLinesHovered, cyan(1.0)),
+            (PlotHistogram, cyan(1.0)),
+            (PlotHistogramHovered, cyan(1.0)),
+            (TextSelectedBg, cyan(0.22)),
+            (ModalWindowDimBg, [0.04, 0.1, 0.09, 0.51]),
+        ],
+    );
+    style
+}
+
+pub fn get_style() -> Style {
+    imgui_style().to_style()
+}
@@ -0,0 +1,84 @@
+//! The Material Flat style from ImThemes.
+
+use egui::Style;
+
+use super::set_colors;
+use crate::{ImGuiCol::*, ImGuiStyle};
+
+/// The variables and colors of the C++ source.
+pub fn imgui_style() -> ImGuiStyle {
+    let mut style = ImGuiStyle {
+        disabled_alpha: 0.5,
+        grab_min_size: 10.0,
+        ..Default::default()
+    };
+    let text = |a: f32| [0.831, 0.847, 0.878, a];
+    let bg = [0.173, 0.192, 0.235, 1.0];
+    let dark = |a: f32| [0.106, 0.114, 0.137, a];
+    let border = |a: f32| [0.204, 0.231, 0.282, a];
+    let button = |a: f32| [0.153, 0.173, 0.212, a];
+    let accent = |a: f32| [0.310, 0.624, 0.933, a];
+    set_colors(
+        &mut style,
+        &[
+            (Text, text(1.0)),
+            (TextDisabled, text(0.502)),
+            (WindowBg, bg),
+            (ChildBg, [0.0, 0.0, 0.0, 0.159]),
+            (PopupBg, bg),
+            (Border, border(1.0)),
+            (BorderShadow, [0.0, 0.0, 0.0, 0.0]),
+            (FrameBg, dark(1.0)),
+            (FrameBgHovered, accent(0.251)),
+            (FrameBgActive, accent(1.0)),
+            (TitleBg, dark(1.0)),
+            (TitleBgActive, dark(1.0)),
+            (TitleBgCollapsed, dark(1.0)),
+            (MenuBarBg, dark(1.0)),
+            (ScrollbarBg, [0.020, 0.020, 0.020, 0.0]),
+            (ScrollbarGrab, [0.533, 0.533, 0.533, 1.0]),
+            (ScrollbarGrabHovered, [0.333, 0.333, 0.333, 1.0]),
+            (ScrollbarGrabActive, [0.600, 0.600, 0.600, 1.0]),
+            (CheckMark, accent(1.0)),
+            (SliderGrab, [0.239, 0.522, 0.878, 1.0]),
+            (SliderGrabActive, [0.259, 0.588, 0.980, 1.0]),
+            (Button, button(0.502)),
+            (ButtonHovered, button(1.0)),
+            (ButtonActive, accent(1.0)),
+            (Header, button(1.0)),
+            (HeaderHovered, accent(0.251)),
+            (HeaderActive, accent(1.0)),
+            (Separator, [0.427, 0.427, 0.498, 0.5]),
+            (SeparatorHovered, [0.098, 0.4, 0.749, 0.78]),
+            (SeparatorActive, [0.098, 0.4, 0.749, 1.0]),
+            (ResizeGrip, dark(1.0)),
+            (ResizeGripHovered, accent(1.0)),
+            (ResizeGripActive, accent(1.0)),
+            (Tab, button(1.0)),
+            (TabHovered, accent(0.251)),
+            (TabActive, accent(1.0)),
+            (TabUnfocused, button(1.0)),
+            (TabUnfocusedActive, accent(1.0)),
+            (PlotLines, [0.608, 0.608, 0.608, 1.0]),
+            (PlotLinesHovered, [1.0, 0.427, 0.349, 1.0]),
+            (PlotHistogram, [0.898, 0.698, 0.0, 1.0]),
+            (PlotHistogramHovered, [1.0, 0.6, 0.0, 1.0]),
+            (TableHeaderBg, dark(1.0)),
+            (TableBorderStrong, border(1.0)),
+            (TableBorderLight, border(0.502)),
+            (TableRowBg, [0.0, 0.0, 0.0, 0.0]),
+            (TableRowBgAlt, [1.0, 1.0, 1.0, 0.039]),
+            (TextSelectedBg, border(1.0)),
+            (DragDropTarget, [1.0, 1.0, 0.0, 0.9]),
+            (NavHighlight, [0.259, 0.588, 0.976, 1.0]),
+            (NavWindowingHighlight, [1.0, 1.0, 1.0, 0.7]),
+            (NavWindowingDimBg, [0.8, 0.8, 0.8, 0.2]),
+            (ModalWindowDimBg, dark(0.502)),
+        ],
+    );
+    style
+}
+
+pub fn get_style() -> Style {
+    imgui_style().to_style()
+}
@@ -0,0 +1,106 @@
+//! The Moonlight style by Madam-Herta.
+
+use egui::{vec2, Style};
+
+use super::set_colors;
+use crate::{ImGuiCol::*, ImGuiStyle};
+
+/// The variables and colors of the C++ source.
+pub fn imgui_style() -> ImGuiStyle {
+    let mut style = ImGuiStyle {
+        alpha: 1.0,
+        disabled_alpha: 1.0,
+        window_padding: vec2(12.0, 12.0),
+        window_rounding: 11.5,
+        window_border_size: 0.0,
+        window_min_size: vec2(20.0, 20.0),
+        window_title_align: vec2(0.5, 0.5),
+        child_rounding: 0.0,
+        child_border_size: 1.0,
+        popup_rounding: 0.0,
+        popup_border_size: 1.0,
+        frame_padding: vec2(20.0, 3.4),
+        frame_rounding: 11.9,
+        frame_border_size: 0.0,
+        item_spacing: vec2(4.3, 5.5),
+        item_inner_spacing: vec2(7.1, 1.8),
+        cell_padding: vec2(12.1, 9.2),
+        indent_spacing: 0.0,
+        columns_min_spacing: 4.9,
+        scrollbar_size: 11.6,
+        scrollbar_rounding: 15.9,
+        grab_min_size: 3.7,
+        grab_rounding: 20.0,
+        tab_rounding: 0.0,
+        tab_border_size: 0.0,
+        ..Default::default()
+    };
+    let bg = [0.078, 0.086, 0.102, 1.0];
+    let dark = [0.047, 0.055, 0.071, 1.0];
+    let grab = [0.118, 0.133, 0.149, 1.0];
+    let hover = [0.157, 0.169, 0.192, 1.0];
+    let yellow = [0.973, 1.0, 0.498, 1.0];
+    set_colors(
+        &mut style,
+        &[
+            (Text, [1.0, 1.0, 1.0, 1.0]),
+            (TextDisabled, [0.273, 0.299, 0.338, 1.0]),
+            (WindowBg, bg),
+            (ChildBg, [0.093, 0.1, 0.116, 1.0]),
+            (PopupBg, bg),
+            (Border, hover),
+            (BorderShadow, bg),
+            (FrameBg, [0.112, 0.126, 0.155, 1.0]),
+            (FrameBgHovered, hover),
+            (FrameBgActive, hover),
+            (TitleBg, dark),
+            (TitleBgActive, dark),
+            (TitleBgCollapsed, bg),
+            (MenuBarBg, [0.098, 0.106, 0.122, 1.0]),
+            (ScrollbarBg, dark),
+            (ScrollbarGrab, grab),
+            (ScrollbarGrabHovered, hover),
+            (ScrollbarGrabActive, grab),
+            (CheckMark, yellow),
+            (SliderGrab, yellow),
+            (SliderGrabActive, [1.0, 0.796, 0.498, 1.0]),
+            (Button, grab),
+            (ButtonHovered, [0.182, 0.189, 0.197, 1.0]),
+            (ButtonActive, [0.155, 0.155, 0.155, 1.0]),
+            (Header, [0.141, 0.163, 0.206, 1.0]),
+            (HeaderHovered, [0.107, 0.107, 0.107, 1.0]),
+            (HeaderActive, bg),
+            (Separator, [0.129, 0.148, 0.193, 1.0]),
+            (SeparatorHovered, [0.157, 0.184, 0.251, 1.0]),
+            (SeparatorActive, [0.157, 0.184, 0.251, 1.0]),
+            (ResizeGrip, [0.146, 0.146, 0.146, 1.0]),
+            (ResizeGripHovered, yellow),
+            (ResizeGripActive, [1.0, 1.0, 1.0, 1.0]),
+            (Tab, bg),
+            (TabHovered, grab),
+            (TabActive, grab),
+            (TabUnfocused, bg),
+            (TabUnfocusedActive, [0.125, 0.274, 0.571, 1.0]),
+            (PlotLines, [0.522, 0.6, 0.702, 1.0]),
+            (PlotLinesHovered, [0.039, 0.98, 0.98, 1.0]),
+            (PlotHistogram, [0.884, 0.794, 0.561, 1.0]),
+            (PlotHistogramHovered, [0.957, 0.957, 0.957, 1.0]),
+            (TableHeaderBg, dark),
+            (TableBorderStrong, dark),
+            (TableBorderLight, [0.0, 0.0, 0.0, 1.0]),
+            (TableRowBg, grab),
+            (TableRowBgAlt, [0.098, 0.106, 0.122, 1.0]),
+            (TextSelectedBg, [0.937, 0.937, 0.937, 1.0]),
+            (DragDropTarget, [0.498, 0.514, 1.0, 1.0]),
+            (NavHighlight, [0.266, 0.289, 1.0, 1.0]),
+            (NavWindowingHighlight, [0.498, 0.514, 1.0, 1.0]),
+            (NavWindowingDimBg, [0.196, 0.176, 0.545, 0.502]),
+            (ModalWindowDimBg, [0.196, 0.176, 0.545, 0.502]),
+        ],
+    );
+    style
+}
+
+pub fn get_style() -> Style {
+    imgui_style().to_style()
+}
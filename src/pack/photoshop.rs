@@ -0,0 +1,85 @@
+//! The Photoshop style by Derydoca.
+
+use egui::Style;
+
+use super::set_colors;
+use crate::{ImGuiCol::*, ImGuiStyle};
+
+/// The variables and colors of the C++ source.
+pub fn imgui_style() -> ImGuiStyle {
+    let mut style = ImGuiStyle {
+        child_rounding: 4.0,
+        frame_border_size: 1.0,
+        frame_rounding: 2.0,
+        grab_min_size: 7.0,
+        popup_rounding: 2.0,
+        scrollbar_rounding: 12.0,
+        scrollbar_size: 13.0,
+        tab_border_size: 1.0,
+        tab_rounding: 0.0,
+        window_rounding: 4.0,
+        ..Default::default()
+    };
+    let border = [0.266, 0.266, 0.266, 1.0];
+    let orange = |a: f32| [1.0, 0.391, 0.0, a];
+    set_colors(
+        &mut style,
+        &[
+            (Text, [1.0, 1.0, 1.0, 1.0]),
+            (TextDisabled, [0.5, 0.5, 0.5, 1.0]),
+            (WindowBg, [0.18, 0.18, 0.18, 1.0]),
+            (ChildBg, [0.28, 0.28, 0.28, 0.0]),
+            (PopupBg, [0.313, 0.313, 0.313, 1.0]),
+            (Border, border),
+            (BorderShadow, [0.0, 0.0, 0.0, 0.0]),
+            (FrameBg, [0.16, 0.16, 0.16, 1.0]),
+            (FrameBgHovered, [0.2, 0.2, 0.2, 1.0]),
+            (FrameBgActive, [0.28, 0.28, 0.28, 1.0]),
+            (TitleBg, [0.148, 0.148, 0.148, 1.0]),
+            (TitleBgActive, [0.148, 0.148, 0.148, 1.0]),
+            (TitleBgCollapsed, [0.148, 0.148, 0.148, 1.0]),
+            (MenuBarBg, [0.195, 0.195, 0.195, 1.0]),
+            (ScrollbarBg, [0.16, 0.16, 0.16, 1.0]),
+            (ScrollbarGrab, [0.277, 0.277, 0.277, 1.0]),
+            (ScrollbarGrabHovered, [0.3, 0.3, 0.3, 1.0]),
+            (ScrollbarGrabActive, orange(1.0)),
+            (CheckMark, [1.0, 1.0, 1.0, 1.0]),
+            (SliderGrab, [0.391, 0.391, 0.391, 1.0]),
+            (SliderGrabActive, orange(1.0)),
+            (Button, [1.0, 1.0, 1.0, 0.0]),
+            (ButtonHovered, [1.0, 1.0, 1.0, 0.156]),
+            (ButtonActive, [1.0, 1.0, 1.0, 0.391]),
+            (Header, [0.313, 0.313, 0.313, 1.0]),
+            (HeaderHovered, [0.469, 0.469, 0.469, 1.0]),
+            (HeaderActive, [0.469, 0.469, 0.469, 1.0]),
+            (Separator, border),
+            (SeparatorHovered, [0.391, 0.391, 0.391, 1.0]),
+            (SeparatorActive, orange(1.0)),
+            (ResizeGrip, [1.0, 1.0, 1.0, 0.25]),
+            (ResizeGripHovered, [1.0, 1.0, 1.0, 0.67]),
+            (ResizeGripActive, orange(1.0)),
+            (Tab, [0.098, 0.098, 0.098, 1.0]),
+            (TabHovered, [0.352, 0.352, 0.352, 1.0]),
+            (TabActive, [0.195, 0.195, 0.195, 1.0]),
+            (TabUnfocused, [0.098, 0.098, 0.098, 1.0]),
+            (TabUnfocusedActive, [0.195, 0.195, 0.195, 1.0]),
+            (DockingPreview, orange(0.781)),
+            (DockingEmptyBg, [0.18, 0.18, 0.18, 1.0]),
+            (PlotLines, [0.469, 0.469, 0.469, 1.0]),
+            (PlotLinesHovered, orange(1.0)),
+            (PlotHistogram, [0.586, 0.586, 0.586, 1.0]),
+            (PlotHistogramHovered, orange(1.0)),
+            (TextSelectedBg, [1.0, 1.0, 1.0, 0.156]),
+            (DragDropTarget, orange(1.0)),
+            (NavHighlight, orange(1.0)),
+            (NavWindowingHighlight, orange(1.0)),
+            (NavWindowingDimBg, [0.0, 0.0, 0.0, 0.586]),
+            (ModalWindowDimBg, [0.0, 0.0, 0.0, 0.586]),
+        ],
+    );
+    style
+}
+
+pub fn get_style() -> Style {
+    imgui_style().to_style()
+}
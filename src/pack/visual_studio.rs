@@ -0,0 +1,86 @@
+//! The Visual Studio style by MomoDeve.
+
+use egui::Style;
+
+use super::set_colors;
+use crate::{ImGuiCol::*, ImGuiStyle};
+
+/// The variables and colors of the C++ source.
+pub fn imgui_style() -> ImGuiStyle {
+    let rgb = |r: u8, g: u8, b: u8| [r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, 1.0];
+    let bg = rgb(37, 37, 38);
+    let light_bg = rgb(82, 82, 85);
+    let very_light_bg = rgb(90, 90, 95);
+    let panel = rgb(51, 51, 55);
+    let panel_hover = rgb(29, 151, 236);
+    let panel_active = rgb(0, 119, 200);
+    let text = rgb(255, 255, 255);
+    let text_disabled = rgb(151, 151, 151);
+    let border = rgb(78, 78, 78);
+    let mut style = ImGuiStyle {
+        window_rounding: 0.0,
+        child_rounding: 0.0,
+        frame_rounding: 0.0,
+        grab_rounding: 0.0,
+        popup_rounding: 0.0,
+        scrollbar_rounding: 0.0,
+        tab_rounding: 0.0,
+        ..Default::default()
+    };
+    set_colors(
+        &mut style,
+        &[
+            (Text, text),
+            (TextDisabled, text_disabled),
+            (TextSelectedBg, panel_active),
+            (WindowBg, bg),
+            (ChildBg, bg),
+            (PopupBg, bg),
+            (Border, border),
+            (BorderShadow, border),
+            (FrameBg, panel),
+            (FrameBgHovered, panel_hover),
+            (FrameBgActive, panel_active),
+            (TitleBg, bg),
+            (TitleBgActive, bg),
+            (TitleBgCollapsed, bg),
+            (MenuBarBg, panel),
+            (ScrollbarBg, panel),
+            (ScrollbarGrab, light_bg),
+            (ScrollbarGrabHovered, very_light_bg),
+            (ScrollbarGrabActive, very_light_bg),
+            (CheckMark, panel_active),
+            (SliderGrab, panel_hover),
+            (SliderGrabActive, panel_active),
+            (Button, panel),
+            (ButtonHovered, panel_hover),
+            (ButtonActive, panel_hover),
+            (Header, panel),
+            (HeaderHovered, panel_hover),
+            (HeaderActive, panel_active),
+            (Separator, border),
+            (SeparatorHovered, border),
+            (SeparatorActive, border),
+            (ResizeGrip, bg),
+            (ResizeGripHovered, panel),
+            (ResizeGripActive, light_bg),
+            (PlotLines, panel_active),
+            (PlotLinesHovered, panel_hover),
+            (PlotHistogram, panel_active),
+            (PlotHistogramHovered, panel_hover),
+            (DragDropTarget, bg),
+            (NavHighlight, bg),
+            (DockingPreview, panel_active),
+            (Tab, bg),
+            (TabActive, panel_active),
+            (TabUnfocused, bg),
+            (TabUnfocusedActive, panel_active),
+            (TabHovered, panel_hover),
+        ],
+    );
+    style
+}
+
+pub fn get_style() -> Style {
+    imgui_style().to_style()
+}
@@ -0,0 +1,287 @@
+use egui::{Color32, Style};
+
+type Role = fn(&mut Palette) -> &mut Color32;
+type Field = fn(&mut Style) -> &mut Color32;
+
+/// Every role with the fields of a style it owns.
+const ROLES: [(&str, Role, &[Field]); 17] = [
+    (
+        "window_bg",
+        |palette| &mut palette.window_bg,
+        &[|style| &mut style.visuals.window_fill],
+    ),
+    (
+        "widget_bg",
+        |palette| &mut palette.widget_bg,
+        &[|style| &mut style.visuals.widgets.noninteractive.weak_bg_fill],
+    ),
+    (
+        "extreme_bg",
+        |palette| &mut palette.extreme_bg,
+        &[|style| &mut style.visuals.extreme_bg_color],
+    ),
+    (
+        "code_bg",
+        |palette| &mut palette.code_bg,
+        &[|style| &mut style.visuals.code_bg_color],
+    ),
+    (
+        "border",
+        |palette| &mut palette.border,
+        &[|style| &mut style.visuals.window_stroke.color],
+    ),
+    (
+        "text",
+        |palette| &mut palette.text,
+        &[
+            |style| &mut style.visuals.widgets.noninteractive.fg_stroke.color,
+            |style| &mut style.visuals.widgets.inactive.fg_stroke.color,
+            |style| &mut style.visuals.widgets.open.fg_stroke.color,
+        ],
+    ),
+    (
+        "text_active",
+        |palette| &mut palette.text_active,
+        &[
+            |style| &mut style.visuals.widgets.active.fg_stroke.color,
+            |style| &mut style.visuals.widgets.hovered.fg_stroke.color,
+        ],
+    ),
+    (
+        "frame_bg",
+        |palette| &mut palette.frame_bg,
+        &[|style| &mut style.visuals.widgets.inactive.bg_fill],
+    ),
+    (
+        "frame_bg_hovered",
+        |palette| &mut palette.frame_bg_hovered,
+        &[|style| &mut style.visuals.widgets.hovered.bg_fill],
+    ),
+    (
+        "frame_bg_active",
+        |palette| &mut palette.frame_bg_active,
+        &[|style| &mut style.visuals.widgets.active.bg_fill],
+    ),
+    (
+        "button",
+        |palette| &mut palette.button,
+        &[|style| &mut style.visuals.widgets.inactive.weak_bg_fill],
+    ),
+    (
+        "button_hovered",
+        |palette| &mut palette.button_hovered,
+        &[|style| &mut style.visuals.widgets.hovered.weak_bg_fill],
+    ),
+    (
+        "button_active",
+        |palette| &mut palette.button_active,
+        &[|style| &mut style.visuals.widgets.active.weak_bg_fill],
+    ),
+    (
+        "selection",
+        |palette| &mut palette.selection,
+        &[|style| &mut style.visuals.selection.bg_fill],
+    ),
+    (
+        "hyperlink",
+        |palette| &mut palette.hyperlink,
+        &[|style| &mut style.visuals.hyperlink_color],
+    ),
+    (
+        "warn",
+        |palette| &mut palette.warn,
+        &[|style| &mut style.visuals.warn_fg_color],
+    ),
+    (
+        "error",
+        |palette| &mut palette.error,
+        &[|style| &mut style.visuals.error_fg_color],
+    ),
+];
+
+/// The colors of a theme by their role.
+///
+/// The roles follow the color slots of Dear ImGui. A palette can be turned into a
+/// style with the same spacing and shapes as the built-in color themes
+/// using [`Palette::get_style`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    /// Fill of windows and panels.
+    pub window_bg: Color32,
+    /// Fill of non-interactive widgets like frames and group boxes.
+    pub widget_bg: Color32,
+    /// Fill of text edits, scroll bar backgrounds and other sunken areas.
+    pub extreme_bg: Color32,
+    /// Fill behind code.
+    pub code_bg: Color32,
+    /// Borders of windows. [`Palette::get_style`] also draws separators and
+    /// non-interactive widgets with it.
+    pub border: Color32,
+    /// Text of widgets in their normal state.
+    pub text: Color32,
+    /// Text of hovered and active widgets.
+    pub text_active: Color32,
+    /// Background of checkboxes, sliders and other framed widgets.
+    pub frame_bg: Color32,
+    pub frame_bg_hovered: Color32,
+    pub frame_bg_active: Color32,
+    /// Fill of buttons.
+    pub button: Color32,
+    pub button_hovered: Color32,
+    pub button_active: Color32,
+    /// Fill of selected text and selected items.
+    pub selection: Color32,
+    pub hyperlink: Color32,
+    pub warn: Color32,
+    pub error: Color32,
+}
+
+impl Palette {
+    /// Get a style with these colors and the spacing and shapes of the color themes.
+    pub fn get_style(&self) -> Style {
+        crate::colors::get_style_from_palette(self)
+    }
+
+    /// Read the colors of `style` from the first field each role owns, see [`Palette::apply`].
+    pub fn from_style(style: &Style) -> Self {
+        let mut style = style.clone();
+        let mut palette = Self {
+            window_bg: Color32::PLACEHOLDER,
+            widget_bg: Color32::PLACEHOLDER,
+            extreme_bg: Color32::PLACEHOLDER,
+            code_bg: Color32::PLACEHOLDER,
+            border: Color32::PLACEHOLDER,
+            text: Color32::PLACEHOLDER,
+            text_active: Color32::PLACEHOLDER,
+            frame_bg: Color32::PLACEHOLDER,
+            frame_bg_hovered: Color32::PLACEHOLDER,
+            frame_bg_active: Color32::PLACEHOLDER,
+            button: Color32::PLACEHOLDER,
+            button_hovered: Color32::PLACEHOLDER,
+            button_active: Color32::PLACEHOLDER,
+            selection: Color32::PLACEHOLDER,
+            hyperlink: Color32::PLACEHOLDER,
+            warn: Color32::PLACEHOLDER,
+            error: Color32::PLACEHOLDER,
+        };
+        for (_, role, fields) in ROLES {
+            *role(&mut palette) = *fields[0](&mut style);
+        }
+        palette
+    }
+
+    /// Recolor `style` with these colors while keeping its spacing and shapes.
+    ///
+    /// Each role is written to the fields it owns, see [`Palette::apply_role`].
+    /// [`Palette::get_style`] derives the colors of the open widgets and the
+    /// selection stroke from the roles as well, but they are not owned by a role.
+    pub fn apply(&self, style: &mut Style) {
+        for (name, ..) in ROLES {
+            self.apply_role(name, style);
+        }
+    }
+
+    /// Write the role named `name` to the fields it owns and leave the rest of `style` as it is.
+    ///
+    /// | Role | Fields |
+    /// |---|---|
+    /// | `window_bg` | `window_fill` |
+    /// | `widget_bg` | `weak_bg_fill` of the noninteractive widgets |
+    /// | `extreme_bg` | `extreme_bg_color` |
+    /// | `code_bg` | `code_bg_color` |
+    /// | `border` | `window_stroke` |
+    /// | `text` | `fg_stroke` of the noninteractive, inactive and open widgets |
+    /// | `text_active` | `fg_stroke` of the hovered and active widgets |
+    /// | `frame_bg*` | `bg_fill` of the inactive, hovered and active widgets |
+    /// | `button*` | `weak_bg_fill` of the inactive, hovered and active widgets |
+    /// | `selection` | `selection.bg_fill` |
+    /// | `hyperlink`, `warn`, `error` | `hyperlink_color`, `warn_fg_color`, `error_fg_color` |
+    pub fn apply_role(&self, name: &str, style: &mut Style) {
+        let mut palette = *self;
+        for (_, role, fields) in ROLES.iter().filter(|(role, ..)| *role == name) {
+            let color = *role(&mut palette);
+            for field in *fields {
+                *field(style) = color;
+            }
+        }
+    }
+
+    /// The name of every role and a reference to its color.
+    pub fn roles_mut(&mut self) -> [(&'static str, &mut Color32); 17] {
+        [
+            ("window_bg", &mut self.window_bg),
+            ("widget_bg", &mut self.widget_bg),
+            ("extreme_bg", &mut self.extreme_bg),
+            ("code_bg", &mut self.code_bg),
+            ("border", &mut self.border),
+            ("text", &mut self.text),
+            ("text_active", &mut self.text_active),
+            ("frame_bg", &mut self.frame_bg),
+            ("frame_bg_hovered", &mut self.frame_bg_hovered),
+            ("frame_bg_active", &mut self.frame_bg_active),
+            ("button", &mut self.button),
+            ("button_hovered", &mut self.button_hovered),
+            ("button_active", &mut self.button_active),
+            ("selection", &mut self.selection),
+            ("hyperlink", &mut self.hyperlink),
+            ("warn", &mut self.warn),
+            ("error", &mut self.error),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fields::style_fields, fields::FieldValue, Theme};
+
+    #[test]
+    fn applying_the_palette_of_a_style_keeps_it() {
+        for theme in Theme::ALL {
+            let style = theme.get_base_style();
+            let mut applied = style.clone();
+            Palette::from_style(&style).apply(&mut applied);
+            assert!(applied == style, "{theme}");
+        }
+    }
+
+    #[test]
+    fn a_role_only_writes_its_fields() {
+        let style = Theme::Cherry.get_base_style();
+        let mut palette = Palette::from_style(&style);
+        palette.button = Color32::RED;
+        let mut applied = style.clone();
+        palette.apply_role("button", &mut applied);
+        let changed: Vec<String> = style_fields(&style)
+            .into_iter()
+            .zip(style_fields(&applied))
+            .filter(|(before, after)| before != after)
+            .map(|(_, (path, _))| path)
+            .collect();
+        assert_eq!(changed, ["visuals.widgets.inactive.weak_bg_fill"]);
+    }
+
+    #[test]
+    fn every_role_has_fields() {
+        let mut palette = Theme::Imgui.palette().unwrap();
+        let names: Vec<&str> = palette.roles_mut().map(|(name, _)| name).to_vec();
+        let owned: Vec<&str> = ROLES.iter().map(|(name, ..)| *name).collect();
+        assert_eq!(names, owned);
+    }
+
+    #[test]
+    fn no_placeholder_is_left() {
+        let styles = Theme::ALL
+            .map(|theme| theme.get_base_style())
+            .into_iter()
+            .chain([Theme::Imgui.palette().unwrap().get_style()]);
+        for style in styles {
+            for (path, value) in style_fields(&style) {
+                assert!(
+                    value != FieldValue::Color(Color32::PLACEHOLDER),
+                    "{path} is the placeholder"
+                );
+            }
+        }
+    }
+}
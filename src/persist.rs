@@ -0,0 +1,256 @@
+//! Persist the chosen theme through [`eframe::Storage`].
+//!
+//! The `eframe` feature of this crate enables eframe's `persistence` feature, without
+//! which eframe does not provide a storage and nothing is saved.
+//! ```no_run
+//! # use dear_egui::ThemeState;
+//! struct MyApp {
+//!     theme: ThemeState,
+//! }
+//!
+//! impl MyApp {
+//!     fn new(cc: &eframe::CreationContext) -> Self {
+//!         Self { theme: ThemeState::from_creation_context(cc) }
+//!     }
+//! }
+//!
+//! impl eframe::App for MyApp {
+//!     fn save(&mut self, storage: &mut dyn eframe::Storage) {
+//!         self.theme.save(storage);
+//!     }
+//!     // ...
+//! #   fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {}
+//! }
+//! ```
+
+use std::{fmt::Display, str::FromStr};
+
+use egui::Context;
+
+use crate::{set_theme, Font, Theme};
+
+/// The key the theme state is stored under.
+pub const STORAGE_KEY: &str = "dear_egui_theme";
+
+/// The theme, font and scale chosen by the user.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThemeState {
+    pub theme: Theme,
+    pub font: Font,
+    /// The hue of the custom theme, kept while another theme is chosen.
+    pub custom_hue: f32,
+    /// The brightness of the custom theme, kept while another theme is chosen.
+    pub custom_brightness: f32,
+    /// The zoom factor of the context.
+    pub scale: f32,
+}
+
+impl Default for ThemeState {
+    fn default() -> Self {
+        Self {
+            theme: Theme::Imgui,
+            font: Font::default(),
+            custom_hue: 0.0,
+            custom_brightness: 1.0,
+            scale: 1.0,
+        }
+    }
+}
+
+impl ThemeState {
+    /// Load the state saved in `storage`, or the default state, and apply it.
+    pub fn from_creation_context(cc: &eframe::CreationContext<'_>) -> Self {
+        let state = cc.storage.and_then(Self::load).unwrap_or_default();
+        state.apply(&cc.egui_ctx);
+        state
+    }
+
+    /// Load the state saved in `storage`.
+    ///
+    /// Keys that can't be parsed, like a theme that is not registered yet, keep their
+    /// default value.
+    pub fn load(storage: &dyn eframe::Storage) -> Option<Self> {
+        Some(Self::parse_lossy(&storage.get_string(STORAGE_KEY)?).0)
+    }
+
+    /// Parse `key = value` lines like [`ThemeState::from_str`], but keep the default
+    /// value of every key that can't be parsed and return the errors next to the state.
+    pub fn parse_lossy(s: &str) -> (Self, Vec<ParseStateError>) {
+        let mut state = ThemeState::default();
+        let mut errors = Vec::new();
+        for line in s.lines().filter(|line| !line.trim().is_empty()) {
+            let Some((key, value)) = line.split_once('=') else {
+                errors.push(ParseStateError(line.to_owned()));
+                continue;
+            };
+            let value = value.trim();
+            let error = |err: &dyn Display| ParseStateError(format!("{}: {err}", key.trim()));
+            let result = match key.trim() {
+                "theme" => value
+                    .parse()
+                    .map(|theme| state.theme = theme)
+                    .map_err(|err| error(&err)),
+                "font" => value
+                    .parse()
+                    .map(|font| state.font = font)
+                    .map_err(|err| error(&err)),
+                "custom_hue" => {
+                    parse_float(value, &mut state.custom_hue).map_err(|err| error(&err))
+                }
+                "custom_brightness" => {
+                    parse_float(value, &mut state.custom_brightness).map_err(|err| error(&err))
+                }
+                "scale" => parse_scale(value, &mut state.scale).map_err(|err| error(&err)),
+                _ => Ok(()),
+            };
+            errors.extend(result.err());
+        }
+        (state, errors)
+    }
+
+    /// Save the state to `storage`.
+    pub fn save(&self, storage: &mut dyn eframe::Storage) {
+        storage.set_string(STORAGE_KEY, self.to_string());
+    }
+
+    /// The custom theme with the remembered hue and brightness.
+    pub fn custom_theme(&self) -> Theme {
+        Theme::Custom {
+            hue: self.custom_hue,
+            brightness: self.custom_brightness,
+        }
+    }
+
+    /// Set the theme, remembering the hue and brightness if it is a custom theme.
+    pub fn set_theme(&mut self, theme: Theme) {
+        if let Theme::Custom { hue, brightness } = theme {
+            self.custom_hue = hue;
+            self.custom_brightness = brightness;
+        }
+        self.theme = theme;
+    }
+
+    /// Apply the theme, font and scale to `ctx`.
+    pub fn apply(&self, ctx: &Context) {
+        set_theme(ctx, self.theme, self.font);
+        ctx.set_zoom_factor(self.scale);
+    }
+}
+
+/// The error returned when parsing a saved theme state fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseStateError(String);
+
+impl Display for ParseStateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid theme state: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseStateError {}
+
+/// Formats the state as `key = value` lines that can be parsed with [`ThemeState::from_str`].
+impl Display for ThemeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "theme = {}", self.theme)?;
+        writeln!(f, "font = {}", self.font)?;
+        writeln!(f, "custom_hue = {}", self.custom_hue)?;
+        writeln!(f, "custom_brightness = {}", self.custom_brightness)?;
+        writeln!(f, "scale = {}", self.scale)
+    }
+}
+
+/// Parses `key = value` lines. Missing keys keep their default value and unknown keys
+/// are ignored so that states saved by other versions can still be loaded.
+///
+/// Fails on the first line that can't be parsed, see [`ThemeState::parse_lossy`]
+/// to keep the other keys instead.
+impl FromStr for ThemeState {
+    type Err = ParseStateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (state, errors) = ThemeState::parse_lossy(s);
+        match errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(state),
+        }
+    }
+}
+
+/// Parse a finite number into `value`.
+fn parse_float(text: &str, value: &mut f32) -> Result<(), String> {
+    match text.parse::<f32>() {
+        Ok(number) if number.is_finite() => {
+            *value = number;
+            Ok(())
+        }
+        _ => Err(format!("Expected a number, found `{text}`")),
+    }
+}
+
+/// Parse a finite zoom factor above zero into `value`.
+fn parse_scale(text: &str, value: &mut f32) -> Result<(), String> {
+    match text.parse::<f32>() {
+        Ok(number) if number.is_finite() && number > 0.0 => {
+            *value = number;
+            Ok(())
+        }
+        _ => Err(format!("Expected a positive number, found `{text}`")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn states_round_trip() {
+        let mut state = ThemeState {
+            font: Font::ProggyClean,
+            scale: 1.25,
+            ..Default::default()
+        };
+        state.set_theme(Theme::Custom {
+            hue: 212.5,
+            brightness: 0.8,
+        });
+        assert_eq!(state.to_string().parse(), Ok(state));
+        state.set_theme(Theme::from_accent(egui::Color32::from_rgb(60, 120, 216)));
+        assert_eq!(state.to_string().parse(), Ok(state));
+        state.set_theme(Theme::Nord);
+        assert_eq!(state.to_string().parse(), Ok(state));
+        assert_eq!(
+            ThemeState::default().to_string().parse(),
+            Ok(ThemeState::default())
+        );
+    }
+
+    #[test]
+    fn keys_that_fail_keep_their_default() {
+        let text = "theme = Not Registered Yet\nfont = ProggyClean\nbroken\nscale = 2\ncustom_hue = x\ncustom_brightness = NaN\nfuture_key = 1";
+        let (state, errors) = ThemeState::parse_lossy(text);
+        assert_eq!(
+            state,
+            ThemeState {
+                font: Font::ProggyClean,
+                scale: 2.0,
+                ..Default::default()
+            }
+        );
+        assert_eq!(errors.len(), 4);
+        assert!(text.parse::<ThemeState>().is_err());
+        assert_eq!(
+            ThemeState::parse_lossy(""),
+            (ThemeState::default(), Vec::new())
+        );
+    }
+
+    #[test]
+    fn scales_must_be_positive() {
+        for scale in ["0", "-1", "NaN", "inf"] {
+            let (state, errors) = ThemeState::parse_lossy(&format!("scale = {scale}"));
+            assert_eq!(state.scale, 1.0, "{scale}");
+            assert_eq!(errors.len(), 1, "{scale}");
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use std::{collections::HashMap, sync::Arc};
+
+use egui::{
+    vec2, Color32, ComboBox, DragValue, Id, Rect, Response, Rounding, Sense, Style, Ui, Widget,
+};
+
+use crate::{style_stack::StyleGuard, Theme, ThemePreview};
+
+/// How the themes are laid out by a [`ThemePicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PickerLayout {
+    /// A row of tabs, each painted in its own theme.
+    Tabs,
+    /// A combo box listing all themes.
+    ComboBox,
+    /// A grid of theme previews with the given number of columns.
+    Grid { columns: usize },
+}
+
+/// A widget to pick one of the built-in and registered themes.
+///
+/// The picker includes an entry for a custom theme together with an editor
+/// for its hue and brightness.
+/// ```no_run
+/// # use dear_egui::{PickerLayout, Theme, ThemePicker};
+/// # fn f(ui: &mut egui::Ui, theme: &mut Theme) {
+/// ui.add(ThemePicker::new(theme).layout(PickerLayout::ComboBox));
+/// # }
+/// ```
+pub struct ThemePicker<'a> {
+    theme: &'a mut Theme,
+    layout: PickerLayout,
+    custom: bool,
+    id_source: Id,
+}
+
+impl<'a> ThemePicker<'a> {
+    pub fn new(theme: &'a mut Theme) -> Self {
+        Self {
+            theme,
+            layout: PickerLayout::Tabs,
+            custom: true,
+            id_source: Id::new("dear_egui_theme_picker"),
+        }
+    }
+
+    pub fn layout(mut self, layout: PickerLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Show an entry and editor for a custom theme. Default: `true`.
+    pub fn custom(mut self, custom: bool) -> Self {
+        self.custom = custom;
+        self
+    }
+
+    /// Use a different id source if more than one picker is shown in the same `Ui`.
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Id::new(id_source);
+        self
+    }
+
+    /// The themes to pick from. The custom theme remembers the last edited values.
+    fn themes(&self, ui: &Ui, id: Id) -> Vec<Theme> {
+        let mut themes = Theme::all();
+        if self.custom {
+            let custom = match *self.theme {
+                Theme::Custom { .. } => *self.theme,
+                _ => ui.data(|data| data.get_temp(id)).unwrap_or(Theme::Custom {
+                    hue: 0.0,
+                    brightness: 1.0,
+                }),
+            };
+            themes.push(custom);
+        }
+        // An accent theme can't be edited here, but is listed while it is chosen.
+        if let Theme::Accent(_) = self.theme {
+            themes.push(*self.theme);
+        }
+        themes
+    }
+}
+
+impl Widget for ThemePicker<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let id = ui.make_persistent_id(self.id_source);
+        let themes = self.themes(ui, id);
+        let styles = StyleCache::load(ui, id.with("styles"), &themes);
+        let before = *self.theme;
+
+        let mut response = ui
+            .vertical(|ui| match self.layout {
+                PickerLayout::Tabs => tabs(ui, self.theme, &themes, &styles),
+                PickerLayout::ComboBox => combo_box(ui, id, self.theme, &themes, &styles),
+                PickerLayout::Grid { columns } => {
+                    grid(ui, id, self.theme, &themes, &styles, columns)
+                }
+            })
+            .response;
+
+        if let Theme::Custom { hue, brightness } = self.theme {
+            ui.horizontal(|ui| {
+                ui.label("Hue:");
+                ui.add(DragValue::new(hue).clamp_range(0.0..=360.0));
+                ui.label("Brightness:");
+                ui.add(
+                    DragValue::new(brightness)
+                        .clamp_range(0.0..=2.0)
+                        .speed(0.01),
+                );
+            });
+            ui.data_mut(|data| data.insert_temp(id, *self.theme));
+        }
+
+        if *self.theme != before {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// The base styles of the listed themes, kept in the memory of the `Ui` so that the
+/// styles of all themes are not built again in every frame.
+#[derive(Clone, Default)]
+struct StyleCache(HashMap<Theme, Arc<Style>>);
+
+impl StyleCache {
+    fn load(ui: &Ui, id: Id, themes: &[Theme]) -> Self {
+        let mut cache: Self = ui.data(|data| data.get_temp(id)).unwrap_or_default();
+        // Forget the previous values of the custom theme and unregistered themes.
+        cache.0.retain(|theme, _| themes.contains(theme));
+        for theme in themes {
+            cache
+                .0
+                .entry(*theme)
+                .or_insert_with(|| Arc::new(theme.get_base_style()));
+        }
+        ui.data_mut(|data| data.insert_temp(id, cache.clone()));
+        cache
+    }
+
+    fn get(&self, theme: &Theme) -> Arc<Style> {
+        self.0
+            .get(theme)
+            .cloned()
+            .unwrap_or_else(|| Arc::new(theme.get_base_style()))
+    }
+}
+
+fn tabs(ui: &mut Ui, current: &mut Theme, themes: &[Theme], styles: &StyleCache) {
+    let row = ui.horizontal_wrapped(|ui| {
+        // Move tabs that don't fit to the next row instead of wrapping their names.
+        ui.style_mut().wrap = Some(false);
+        for theme in themes {
+            // A child scope would not wrap, so the style is restored by a guard instead.
+            let mut ui = StyleGuard::new(ui, |style| {
+                // Keep the text color of unselected tabs readable on the current background.
+                let text = style.visuals.widgets.inactive.fg_stroke;
+                let saved =
+                    std::mem::replace(&mut style.visuals, styles.get(theme).visuals.clone());
+                let widgets = &mut style.visuals.widgets;
+                widgets.inactive.fg_stroke = text;
+                for visuals in [
+                    &mut widgets.inactive,
+                    &mut widgets.hovered,
+                    &mut widgets.active,
+                    &mut widgets.open,
+                ] {
+                    visuals.rounding = Rounding {
+                        se: 0.0,
+                        sw: 0.0,
+                        ..visuals.rounding
+                    };
+                }
+                move |style: &mut Style| style.visuals = saved
+            });
+            if ui
+                .selectable_label(current == theme, theme.name())
+                .clicked()
+            {
+                *current = *theme;
+            }
+        }
+    });
+
+    // Underline the tabs with the accent color of the selected theme.
+    let accent = styles.get(current).visuals.selection.bg_fill;
+    let rect = Rect::from_min_size(
+        row.response.rect.left_bottom(),
+        vec2(ui.available_width(), 2.0),
+    );
+    ui.painter().rect_filled(rect, 0.0, accent);
+    ui.add_space((rect.height() - ui.spacing().item_spacing.y).max(0.0));
+}
+
+fn combo_box(ui: &mut Ui, id: Id, current: &mut Theme, themes: &[Theme], styles: &StyleCache) {
+    ComboBox::from_id_source(id.with("combo"))
+        .selected_text(current.name())
+        .show_ui(ui, |ui| {
+            for theme in themes {
+                ui.horizontal(|ui| {
+                    swatch(
+                        ui,
+                        &styles.get(theme),
+                        vec2(ui.spacing().icon_width, ui.spacing().icon_width),
+                    );
+                    if ui
+                        .selectable_label(current == theme, theme.name())
+                        .clicked()
+                    {
+                        *current = *theme;
+                    }
+                });
+            }
+        });
+}
+
+fn grid(
+    ui: &mut Ui,
+    id: Id,
+    current: &mut Theme,
+    themes: &[Theme],
+    styles: &StyleCache,
+    columns: usize,
+) {
+    egui::Grid::new(id.with("grid")).show(ui, |ui| {
+        for (index, theme) in themes.iter().enumerate() {
+            ui.vertical(|ui| {
+                let response = ui.add(
+                    ThemePreview::from_style(Style::clone(&styles.get(theme)))
+                        .size(vec2(72.0, 48.0))
+                        .sense(Sense::click()),
+                );
+                let selected = current == theme;
+                if selected || response.hovered() {
+                    let visuals = ui.visuals().widgets.style(&response);
+                    let stroke = if selected {
+                        ui.visuals().selection.stroke
+                    } else {
+                        visuals.fg_stroke
+                    };
+                    ui.painter()
+                        .rect_stroke(response.rect.expand(1.0), visuals.rounding, stroke);
+                }
+                let label = ui.selectable_label(selected, theme.name());
+                if response.clicked() || label.clicked() {
+                    *current = *theme;
+                }
+            });
+            if (index + 1) % columns.max(1) == 0 {
+                ui.end_row();
+            }
+        }
+    });
+}
+
+/// Paint the main colors of a style as vertical stripes.
+fn swatch(ui: &mut Ui, style: &Style, size: egui::Vec2) -> Response {
+    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+    if ui.is_rect_visible(rect) {
+        let visuals = &style.visuals;
+        let colors: [Color32; 4] = [
+            visuals.window_fill,
+            visuals.widgets.inactive.bg_fill,
+            visuals.widgets.inactive.weak_bg_fill,
+            visuals.selection.bg_fill,
+        ];
+        let width = rect.width() / colors.len() as f32;
+        for (index, color) in colors.into_iter().enumerate() {
+            let stripe = Rect::from_min_size(
+                rect.min + vec2(width * index as f32, 0.0),
+                vec2(width, rect.height()),
+            );
+            ui.painter().rect_filled(stripe, 0.0, color);
+        }
+        ui.painter()
+            .rect_stroke(rect, 0.0, ui.visuals().widgets.noninteractive.bg_stroke);
+    }
+    response
+}
@@ -0,0 +1,182 @@
+use egui::{
+    emath::RectTransform, pos2, style::HandleShape, vec2, Painter, Pos2, Rect, Response, Rounding,
+    Sense, Shape, Stroke, Style, Ui, Vec2, Widget,
+};
+
+use crate::Theme;
+
+/// A miniature mock-up of a window painted with a style.
+///
+/// The preview shows a window with a title bar, a button, a slider, a checkbox and
+/// a selected row without applying the style to the `Ui`.
+/// ```no_run
+/// # use dear_egui::{Theme, ThemePreview};
+/// # use egui::vec2;
+/// # fn f(ui: &mut egui::Ui) {
+/// ui.add(ThemePreview::new(Theme::Forest).size(vec2(96.0, 64.0)));
+/// # }
+/// ```
+pub struct ThemePreview {
+    style: Style,
+    size: Vec2,
+    sense: Sense,
+}
+
+impl ThemePreview {
+    pub fn new(theme: Theme) -> Self {
+        Self::from_style(theme.get_base_style())
+    }
+
+    pub fn from_style(style: Style) -> Self {
+        Self {
+            style,
+            size: vec2(96.0, 64.0),
+            sense: Sense::hover(),
+        }
+    }
+
+    /// The size of the preview. Default: 96x64 points.
+    pub fn size(mut self, size: Vec2) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Make the preview interactive.
+    pub fn sense(mut self, sense: Sense) -> Self {
+        self.sense = sense;
+        self
+    }
+}
+
+impl Widget for ThemePreview {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let (rect, response) = ui.allocate_exact_size(self.size, self.sense);
+        if ui.is_rect_visible(rect) {
+            paint_preview(ui.painter(), rect, &self.style);
+        }
+        response
+    }
+}
+
+/// Paint a miniature window in the style of `style` into `rect`.
+///
+/// The mock-up is laid out on a 96x64 grid that is scaled to fit `rect`.
+pub fn paint_preview(painter: &Painter, rect: Rect, style: &Style) {
+    let painter = painter.with_clip_rect(rect);
+    let visuals = &style.visuals;
+    let widgets = &visuals.widgets;
+    let to_screen = RectTransform::from_to(Rect::from_min_size(Pos2::ZERO, vec2(96.0, 64.0)), rect);
+    let scale = to_screen.scale().min_elem();
+    let rect_at = |x: f32, y: f32, w: f32, h: f32| {
+        to_screen.transform_rect(Rect::from_min_size(pos2(x, y), vec2(w, h)))
+    };
+    let rounding = |rounding: Rounding| Rounding {
+        nw: rounding.nw * scale,
+        ne: rounding.ne * scale,
+        sw: rounding.sw * scale,
+        se: rounding.se * scale,
+    };
+    let stroke = |stroke: Stroke| Stroke::new(stroke.width * scale.min(1.0), stroke.color);
+    // Text is too small to be readable and is drawn as a bar instead.
+    let text = |x: f32, y: f32, w: f32, stroke: Stroke| {
+        painter.rect_filled(rect_at(x, y, w, 2.0), 0.0, stroke.color);
+    };
+
+    // Window
+    painter.rect(
+        rect,
+        rounding(visuals.window_rounding),
+        visuals.window_fill,
+        stroke(visuals.window_stroke),
+    );
+    let title = rect_at(0.0, 0.0, 96.0, 10.0);
+    painter.rect_filled(
+        title,
+        Rounding {
+            sw: 0.0,
+            se: 0.0,
+            ..rounding(visuals.window_rounding)
+        },
+        widgets.inactive.bg_fill,
+    );
+    text(4.0, 4.0, 28.0, widgets.noninteractive.fg_stroke);
+
+    // Button
+    painter.rect(
+        rect_at(6.0, 15.0, 30.0, 11.0),
+        rounding(widgets.inactive.rounding),
+        widgets.inactive.weak_bg_fill,
+        stroke(widgets.inactive.bg_stroke),
+    );
+    text(11.0, 19.5, 20.0, widgets.inactive.fg_stroke);
+    // Hovered button
+    painter.rect(
+        rect_at(40.0, 15.0, 30.0, 11.0),
+        rounding(widgets.hovered.rounding),
+        widgets.hovered.weak_bg_fill,
+        stroke(widgets.hovered.bg_stroke),
+    );
+    text(45.0, 19.5, 20.0, widgets.hovered.fg_stroke);
+
+    // Slider
+    let rail = rect_at(6.0, 33.0, 58.0, 3.0);
+    painter.rect_filled(
+        rail,
+        rounding(widgets.inactive.rounding),
+        widgets.inactive.bg_fill,
+    );
+    let value = 0.6;
+    if visuals.slider_trailing_fill {
+        let trailing = Rect::from_min_max(
+            rail.min,
+            pos2(rail.lerp_inside(vec2(value, 0.0)).x, rail.max.y),
+        );
+        painter.rect_filled(
+            trailing,
+            rounding(widgets.inactive.rounding),
+            visuals.selection.bg_fill,
+        );
+    }
+    let handle = pos2(rail.lerp_inside(vec2(value, 0.5)).x, rail.center().y);
+    let radius = 4.0 * scale;
+    match visuals.handle_shape {
+        HandleShape::Circle => {
+            painter.add(Shape::circle_filled(handle, radius, widgets.active.bg_fill));
+        }
+        HandleShape::Rect { aspect_ratio } => {
+            let handle =
+                Rect::from_center_size(handle, vec2(2.0 * radius * aspect_ratio, 2.0 * radius));
+            painter.rect_filled(
+                handle,
+                rounding(widgets.active.rounding),
+                widgets.active.bg_fill,
+            );
+        }
+    }
+
+    // Checkbox
+    let check = rect_at(6.0, 41.0, 8.0, 8.0);
+    painter.rect(
+        check,
+        rounding(widgets.inactive.rounding),
+        widgets.inactive.bg_fill,
+        stroke(widgets.inactive.bg_stroke),
+    );
+    painter.add(Shape::line(
+        vec![
+            to_screen.transform_pos(pos2(7.5, 45.0)),
+            to_screen.transform_pos(pos2(9.5, 47.0)),
+            to_screen.transform_pos(pos2(12.5, 43.0)),
+        ],
+        Stroke::new(scale.min(1.0), widgets.inactive.fg_stroke.color),
+    ));
+    text(17.0, 44.0, 24.0, widgets.noninteractive.fg_stroke);
+
+    // Selected row
+    painter.rect_filled(
+        rect_at(4.0, 53.0, 88.0, 8.0),
+        0.0,
+        visuals.selection.bg_fill,
+    );
+    text(8.0, 56.0, 32.0, visuals.selection.stroke);
+}
@@ -0,0 +1,188 @@
+//! A runtime registry for themes defined by the application.
+//!
+//! Registered themes are listed by [`Theme::all`] next to the built-in themes and
+//! can be parsed from their name, so theme pickers and config files treat them
+//! the same as the built-in themes.
+//! ```no_run
+//! # use dear_egui::{registry::ThemeProvider, Font, Palette};
+//! # use egui::Style;
+//! # fn f(ctx: &egui::Context) {
+//! struct Brand;
+//!
+//! impl ThemeProvider for Brand {
+//!     fn name(&self) -> &str {
+//!         "Brand"
+//!     }
+//!
+//!     fn palette(&self) -> Option<Palette> {
+//!         Some(Palette { ..dear_egui::imgui::get_palette() })
+//!     }
+//!
+//!     fn get_base_style(&self) -> Style {
+//!         self.palette().unwrap().get_style()
+//!     }
+//! }
+//!
+//! let brand = dear_egui::registry::register_theme(Brand);
+//! dear_egui::set_theme(ctx, brand, Font::OpenSans);
+//! # }
+//! ```
+
+use std::sync::{Arc, PoisonError, RwLock};
+
+use egui::Style;
+
+use crate::{Font, Palette, Theme};
+
+/// A theme that can be registered with [`register_theme`].
+pub trait ThemeProvider: Send + Sync {
+    /// The display name of the theme. Names are compared ignoring case.
+    fn name(&self) -> &str;
+
+    /// Get the colors, spacing and shapes of the theme.
+    /// See [`Theme::get_base_style`].
+    fn get_base_style(&self) -> Style;
+
+    /// The colors of the theme by their role.
+    fn palette(&self) -> Option<Palette> {
+        None
+    }
+
+    /// The font this theme is designed for. It is used when no font is given,
+    /// see [`Theme::get_style`].
+    fn font(&self) -> Option<Font> {
+        None
+    }
+}
+
+struct Entry {
+    name: &'static str,
+    provider: Arc<dyn ThemeProvider>,
+}
+
+static REGISTRY: RwLock<Vec<Entry>> = RwLock::new(Vec::new());
+
+/// Register a theme and get the [`Theme`] that refers to it.
+///
+/// Registering a theme with the name of an already registered theme replaces it.
+/// A theme with the name of a built-in theme can be registered but it can not be
+/// parsed from its name.
+pub fn register_theme(provider: impl ThemeProvider + 'static) -> Theme {
+    let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+    let provider: Arc<dyn ThemeProvider> = Arc::new(provider);
+    let existing = registry
+        .iter_mut()
+        .find(|entry| entry.name.eq_ignore_ascii_case(provider.name()));
+    let name = match existing {
+        Some(entry) => {
+            entry.provider = provider;
+            entry.name
+        }
+        None => {
+            // Names live as long as the registry, which lives for the whole program.
+            let name: &'static str = Box::leak(provider.name().to_owned().into_boxed_str());
+            registry.push(Entry { name, provider });
+            name
+        }
+    };
+    Theme::Registered(name)
+}
+
+/// All registered themes in the order they were registered.
+pub fn registered_themes() -> Vec<Theme> {
+    let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+    registry
+        .iter()
+        .map(|entry| Theme::Registered(entry.name))
+        .collect()
+}
+
+/// Find a registered theme by its name, ignoring case.
+pub fn find_theme(name: &str) -> Option<Theme> {
+    let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+    registry
+        .iter()
+        .find(|entry| entry.name.eq_ignore_ascii_case(name))
+        .map(|entry| Theme::Registered(entry.name))
+}
+
+/// Get the provider of a registered theme by its name, ignoring case.
+pub fn provider(name: &str) -> Option<Arc<dyn ThemeProvider>> {
+    let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+    registry
+        .iter()
+        .find(|entry| entry.name.eq_ignore_ascii_case(name))
+        .map(|entry| entry.provider.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Named(&'static str);
+
+    impl ThemeProvider for Named {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn get_base_style(&self) -> Style {
+            crate::imgui::get_style()
+        }
+    }
+
+    #[test]
+    fn names_are_compared_ignoring_case() {
+        let theme = register_theme(Named("Registry Test"));
+        assert_eq!(theme, Theme::Registered("Registry Test"));
+        assert_eq!(find_theme("registry test"), Some(theme));
+        assert!(provider("REGISTRY TEST").is_some());
+        assert_eq!(
+            "registry TEST".parse::<Theme>(),
+            Ok(Theme::Registered("Registry Test"))
+        );
+        assert_eq!(register_theme(Named("registry test")), theme);
+        assert!(provider("Registry Test 2").is_none());
+    }
+
+    #[test]
+    fn registered_themes_are_equal_ignoring_case() {
+        use std::hash::{BuildHasher, RandomState};
+
+        let upper = Theme::Registered("Brand");
+        let lower = Theme::Registered("brand");
+        assert_eq!(upper, lower);
+        assert_eq!(upper.cmp(&lower), std::cmp::Ordering::Equal);
+        let hasher = RandomState::new();
+        assert_eq!(hasher.hash_one(upper), hasher.hash_one(lower));
+        assert_ne!(upper, Theme::Registered("Brand 2"));
+    }
+
+    struct WithFont;
+
+    impl ThemeProvider for WithFont {
+        fn name(&self) -> &str {
+            "Registry Font Test"
+        }
+
+        fn get_base_style(&self) -> Style {
+            crate::imgui::get_style()
+        }
+
+        fn font(&self) -> Option<Font> {
+            Some(Font::ProggyClean)
+        }
+    }
+
+    #[test]
+    fn the_font_of_a_provider_is_used_without_a_font() {
+        let theme = register_theme(WithFont);
+        assert_eq!(theme.font(), Some(Font::ProggyClean));
+        assert!(
+            theme.get_style().text_styles
+                == theme
+                    .get_style_with_font(&Font::ProggyClean.into())
+                    .text_styles
+        );
+    }
+}
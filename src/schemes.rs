@@ -0,0 +1,214 @@
+//! Palettes of popular editor color schemes.
+//!
+//! The colors are taken from the published palettes. Shades a scheme doesn't
+//! define, like the fill of buttons, are mixed from its background and accent.
+//! Turn a palette into a style with [`Palette::get_style`].
+
+use egui::Color32;
+
+use crate::Palette;
+
+/// The colors a scheme is built from.
+pub(crate) struct Scheme {
+    pub bg: Color32,
+    /// Fill of text edits, darker than `bg` in dark schemes and lighter in light ones.
+    pub extreme_bg: Color32,
+    pub code_bg: Color32,
+    /// Three surfaces stepping away from `bg`.
+    pub surfaces: [Color32; 3],
+    pub border: Color32,
+    pub text: Color32,
+    pub text_active: Color32,
+    pub accent: Color32,
+    pub hyperlink: Color32,
+    pub warn: Color32,
+    pub error: Color32,
+}
+
+impl Scheme {
+    pub fn palette(&self) -> Palette {
+        let accent = |t| mix(self.bg, self.accent, t);
+        Palette {
+            window_bg: self.bg,
+            widget_bg: self.surfaces[0],
+            extreme_bg: self.extreme_bg,
+            code_bg: self.code_bg,
+            border: self.border,
+            text: self.text,
+            text_active: self.text_active,
+            frame_bg: self.surfaces[0],
+            frame_bg_hovered: self.surfaces[1],
+            frame_bg_active: self.surfaces[2],
+            button: accent(0.40),
+            button_hovered: accent(0.60),
+            button_active: accent(0.75),
+            selection: accent(0.55),
+            hyperlink: self.hyperlink,
+            warn: self.warn,
+            error: self.error,
+        }
+    }
+}
+
+const fn hex(rgb: u32) -> Color32 {
+    Color32::from_rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+}
+
+/// Linear interpolation between two opaque colors.
+pub(crate) fn mix(a: Color32, b: Color32, t: f32) -> Color32 {
+    let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    Color32::from_rgb(
+        channel(a.r(), b.r()),
+        channel(a.g(), b.g()),
+        channel(a.b(), b.b()),
+    )
+}
+
+/// [Nord](https://www.nordtheme.com/docs/colors-and-palettes), which has no light variant.
+pub fn nord() -> Palette {
+    Scheme {
+        bg: hex(0x2e3440),
+        extreme_bg: hex(0x242933),
+        code_bg: hex(0x3b4252),
+        surfaces: [hex(0x3b4252), hex(0x434c5e), hex(0x4c566a)],
+        border: hex(0x4c566a),
+        text: hex(0xd8dee9),
+        text_active: hex(0xeceff4),
+        accent: hex(0x88c0d0),
+        hyperlink: hex(0x81a1c1),
+        warn: hex(0xebcb8b),
+        error: hex(0xbf616a),
+    }
+    .palette()
+}
+
+/// [Dracula](https://draculatheme.com/contribute#color-palette), which has no light variant.
+pub fn dracula() -> Palette {
+    Scheme {
+        bg: hex(0x282a36),
+        extreme_bg: hex(0x191a21),
+        code_bg: hex(0x21222c),
+        surfaces: [hex(0x343746), hex(0x424450), hex(0x44475a)],
+        border: hex(0x44475a),
+        text: hex(0xf8f8f2),
+        text_active: hex(0xffffff),
+        accent: hex(0xbd93f9),
+        hyperlink: hex(0x8be9fd),
+        warn: hex(0xffb86c),
+        error: hex(0xff5555),
+    }
+    .palette()
+}
+
+/// [Solarized](https://ethanschoonover.com/solarized/) dark.
+pub fn solarized_dark() -> Palette {
+    let base02 = hex(0x073642);
+    let base01 = hex(0x586e75);
+    Scheme {
+        bg: hex(0x002b36),
+        extreme_bg: hex(0x00212b),
+        code_bg: base02,
+        surfaces: [base02, mix(base02, base01, 0.25), mix(base02, base01, 0.5)],
+        border: mix(base02, base01, 0.5),
+        text: hex(0x839496),
+        text_active: hex(0x93a1a1),
+        accent: hex(0x268bd2),
+        hyperlink: hex(0x2aa198),
+        warn: hex(0xb58900),
+        error: hex(0xdc322f),
+    }
+    .palette()
+}
+
+/// [Solarized](https://ethanschoonover.com/solarized/) light.
+pub fn solarized_light() -> Palette {
+    let base3 = hex(0xfdf6e3);
+    let base2 = hex(0xeee8d5);
+    let base1 = hex(0x93a1a1);
+    Scheme {
+        bg: base3,
+        extreme_bg: mix(base3, Color32::WHITE, 0.6),
+        code_bg: base2,
+        surfaces: [base2, mix(base2, base1, 0.25), mix(base2, base1, 0.5)],
+        border: base1,
+        text: hex(0x657b83),
+        text_active: hex(0x586e75),
+        accent: hex(0x268bd2),
+        hyperlink: hex(0x2aa198),
+        warn: hex(0xb58900),
+        error: hex(0xdc322f),
+    }
+    .palette()
+}
+
+/// [Gruvbox](https://github.com/morhetz/gruvbox) dark.
+pub fn gruvbox_dark() -> Palette {
+    Scheme {
+        bg: hex(0x282828),
+        extreme_bg: hex(0x1d2021),
+        code_bg: hex(0x3c3836),
+        surfaces: [hex(0x3c3836), hex(0x504945), hex(0x665c54)],
+        border: hex(0x504945),
+        text: hex(0xebdbb2),
+        text_active: hex(0xfbf1c7),
+        accent: hex(0xd79921),
+        hyperlink: hex(0x83a598),
+        warn: hex(0xfe8019),
+        error: hex(0xfb4934),
+    }
+    .palette()
+}
+
+/// [Gruvbox](https://github.com/morhetz/gruvbox) light.
+pub fn gruvbox_light() -> Palette {
+    Scheme {
+        bg: hex(0xfbf1c7),
+        extreme_bg: hex(0xf9f5d7),
+        code_bg: hex(0xebdbb2),
+        surfaces: [hex(0xebdbb2), hex(0xd5c4a1), hex(0xbdae93)],
+        border: hex(0xd5c4a1),
+        text: hex(0x3c3836),
+        text_active: hex(0x282828),
+        accent: hex(0xd79921),
+        hyperlink: hex(0x076678),
+        warn: hex(0xaf3a03),
+        error: hex(0x9d0006),
+    }
+    .palette()
+}
+
+/// [Catppuccin](https://catppuccin.com/palette) Mocha, the darkest flavor.
+pub fn catppuccin_mocha() -> Palette {
+    Scheme {
+        bg: hex(0x1e1e2e),
+        extreme_bg: hex(0x11111b),
+        code_bg: hex(0x181825),
+        surfaces: [hex(0x313244), hex(0x45475a), hex(0x585b70)],
+        border: hex(0x45475a),
+        text: hex(0xbac2de),
+        text_active: hex(0xcdd6f4),
+        accent: hex(0xcba6f7),
+        hyperlink: hex(0x89b4fa),
+        warn: hex(0xfab387),
+        error: hex(0xf38ba8),
+    }
+    .palette()
+}
+
+/// [Catppuccin](https://catppuccin.com/palette) Latte, the light flavor.
+pub fn catppuccin_latte() -> Palette {
+    Scheme {
+        bg: hex(0xeff1f5),
+        extreme_bg: mix(hex(0xeff1f5), Color32::WHITE, 0.6),
+        code_bg: hex(0xe6e9ef),
+        surfaces: [hex(0xccd0da), hex(0xbcc0cc), hex(0xacb0be)],
+        border: hex(0xbcc0cc),
+        text: hex(0x5c5f77),
+        text_active: hex(0x4c4f69),
+        accent: hex(0x8839ef),
+        hyperlink: hex(0x1e66f5),
+        warn: hex(0xfe640b),
+        error: hex(0xd20f39),
+    }
+    .palette()
+}
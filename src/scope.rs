@@ -0,0 +1,82 @@
+use egui::{Frame, InnerResponse, Margin, Style, Ui};
+
+use crate::Theme;
+
+/// Show `add_contents` in the colors and spacing of another theme.
+///
+/// The text styles of `ui` are kept, so the contents use the same fonts as the
+/// rest of the app. The style of `ui` is restored afterwards.
+/// ```no_run
+/// # use dear_egui::Theme;
+/// # fn f(ui: &mut egui::Ui) {
+/// dear_egui::scope(ui, Theme::Forest, |ui| {
+///     ui.button("Green button");
+/// });
+/// # }
+/// ```
+pub fn scope<R>(
+    ui: &mut Ui,
+    theme: Theme,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> InnerResponse<R> {
+    let style = theme.get_base_style();
+    ui.scope(|ui| {
+        apply_theme(ui.style_mut(), style);
+        add_contents(ui)
+    })
+}
+
+/// Copy everything but the typography of `theme` into `style`.
+fn apply_theme(style: &mut Style, theme: Style) {
+    style.visuals = theme.visuals;
+    style.spacing = theme.spacing;
+    style.interaction = theme.interaction;
+    style.animation_time = theme.animation_time;
+}
+
+/// A frame painted in the window colors of another theme, with its contents in that theme.
+///
+/// Use it to color-code the panels of different parts of an app.
+/// ```no_run
+/// # use dear_egui::{Theme, ThemeFrame};
+/// # fn f(ui: &mut egui::Ui) {
+/// ThemeFrame::new(Theme::Sky).show(ui, |ui| {
+///     ui.label("Network");
+/// });
+/// # }
+/// ```
+pub struct ThemeFrame {
+    theme: Theme,
+    inner_margin: Option<Margin>,
+}
+
+impl ThemeFrame {
+    pub fn new(theme: Theme) -> Self {
+        Self {
+            theme,
+            inner_margin: None,
+        }
+    }
+
+    /// The margin inside the frame. Default: the window margin of the theme.
+    pub fn inner_margin(mut self, inner_margin: impl Into<Margin>) -> Self {
+        self.inner_margin = Some(inner_margin.into());
+        self
+    }
+
+    pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let style = self.theme.get_base_style();
+        let visuals = &style.visuals;
+        let frame = Frame {
+            inner_margin: self.inner_margin.unwrap_or(style.spacing.window_margin),
+            rounding: visuals.window_rounding,
+            fill: visuals.panel_fill,
+            stroke: visuals.window_stroke,
+            ..Default::default()
+        };
+        ui.scope(|ui| {
+            apply_theme(ui.style_mut(), style);
+            frame.show(ui, add_contents).inner
+        })
+    }
+}
@@ -0,0 +1,412 @@
+//! Dear ImGui's `PushStyleColor` and `PushStyleVar` for `egui::Ui`.
+//!
+//! A push returns a guard that derefs to the `Ui` and pops the change when it is
+//! dropped, so ImGui code can be ported line by line. Like in ImGui, a pop only
+//! restores what was pushed and keeps other changes to the style:
+//! ```no_run
+//! # use dear_egui::{ImGuiCol, StyleStackExt, StyleVar};
+//! # use egui::{vec2, Color32};
+//! # fn f(ui: &mut egui::Ui) {
+//! // ImGui::PushStyleColor(ImGuiCol_Button, red);
+//! // ImGui::PushStyleVar(ImGuiStyleVar_FramePadding, ImVec2(8, 4));
+//! let mut ui = ui.push_style_color(ImGuiCol::Button, Color32::RED);
+//! let mut ui = ui.push_style_var(StyleVar::FramePadding, vec2(8.0, 4.0));
+//! ui.button("Red button");
+//! // ImGui::PopStyleVar(); ImGui::PopStyleColor();
+//! drop(ui);
+//! # }
+//! ```
+//! The `with_*` functions scope the change to a closure instead.
+
+use std::ops::{Deref, DerefMut};
+
+use egui::{Color32, Margin, Style, Ui, Vec2};
+
+use crate::imgui_col::{self, ImGuiCol};
+
+/// The style variables of Dear ImGui's `ImGuiStyleVar_` enum that egui has a field for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StyleVar {
+    /// `Vec2`, the `window_margin`.
+    WindowPadding,
+    /// `f32`, the `window_rounding`.
+    WindowRounding,
+    /// `f32`, the width of the `window_stroke`.
+    WindowBorderSize,
+    /// `f32`, the `menu_rounding` of popups and menus.
+    PopupRounding,
+    /// `Vec2`, the `button_padding`.
+    FramePadding,
+    /// `f32`, the `rounding` of the inactive, hovered, active and open widgets.
+    FrameRounding,
+    /// `f32`, the width of the `bg_stroke` of the inactive, hovered, active and open widgets.
+    FrameBorderSize,
+    /// `Vec2`, the `item_spacing`.
+    ItemSpacing,
+    /// `Vec2`, the x component is the `icon_spacing` between a checkbox or radio button and its label.
+    ItemInnerSpacing,
+    /// `f32`, the `indent`.
+    IndentSpacing,
+    /// `f32`, the width of the scroll bars.
+    ScrollbarSize,
+    /// `f32`, the minimum length of the scroll bar handles.
+    GrabMinSize,
+}
+
+/// The value of a [`StyleVar`].
+///
+/// A float given to a `Vec2` variable is used for both components,
+/// and the x component of a `Vec2` given to a float variable is used.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StyleValue {
+    Float(f32),
+    Vec2(Vec2),
+}
+
+impl StyleValue {
+    pub fn float(self) -> f32 {
+        match self {
+            StyleValue::Float(value) => value,
+            StyleValue::Vec2(value) => value.x,
+        }
+    }
+
+    pub fn vec2(self) -> Vec2 {
+        match self {
+            StyleValue::Float(value) => Vec2::splat(value),
+            StyleValue::Vec2(value) => value,
+        }
+    }
+}
+
+impl From<f32> for StyleValue {
+    fn from(value: f32) -> Self {
+        StyleValue::Float(value)
+    }
+}
+
+impl From<Vec2> for StyleValue {
+    fn from(value: Vec2) -> Self {
+        StyleValue::Vec2(value)
+    }
+}
+
+/// Set a style variable in every field it drives.
+pub fn set_var(style: &mut Style, var: StyleVar, value: impl Into<StyleValue>) {
+    let value = value.into();
+    let spacing = &mut style.spacing;
+    let visuals = &mut style.visuals;
+    let widgets = &mut visuals.widgets;
+    let frames = [
+        &mut widgets.inactive,
+        &mut widgets.hovered,
+        &mut widgets.active,
+        &mut widgets.open,
+    ];
+    match var {
+        StyleVar::WindowPadding => {
+            let padding = value.vec2();
+            spacing.window_margin = Margin::symmetric(padding.x, padding.y);
+        }
+        StyleVar::WindowRounding => visuals.window_rounding = value.float().into(),
+        StyleVar::WindowBorderSize => visuals.window_stroke.width = value.float(),
+        StyleVar::PopupRounding => visuals.menu_rounding = value.float().into(),
+        StyleVar::FramePadding => spacing.button_padding = value.vec2(),
+        StyleVar::FrameRounding => {
+            for widget in frames {
+                widget.rounding = value.float().into();
+            }
+        }
+        StyleVar::FrameBorderSize => {
+            for widget in frames {
+                widget.bg_stroke.width = value.float();
+            }
+        }
+        StyleVar::ItemSpacing => spacing.item_spacing = value.vec2(),
+        StyleVar::ItemInnerSpacing => spacing.icon_spacing = value.float(),
+        StyleVar::IndentSpacing => spacing.indent = value.float(),
+        StyleVar::ScrollbarSize => spacing.scroll.bar_width = value.float(),
+        StyleVar::GrabMinSize => spacing.scroll.handle_min_length = value.float(),
+    }
+}
+
+/// Get the value of a style variable from the first field it drives.
+pub fn get_var(style: &Style, var: StyleVar) -> StyleValue {
+    let spacing = &style.spacing;
+    let visuals = &style.visuals;
+    match var {
+        StyleVar::WindowPadding => {
+            Vec2::new(spacing.window_margin.left, spacing.window_margin.top).into()
+        }
+        StyleVar::WindowRounding => visuals.window_rounding.nw.into(),
+        StyleVar::WindowBorderSize => visuals.window_stroke.width.into(),
+        StyleVar::PopupRounding => visuals.menu_rounding.nw.into(),
+        StyleVar::FramePadding => spacing.button_padding.into(),
+        StyleVar::FrameRounding => visuals.widgets.inactive.rounding.nw.into(),
+        StyleVar::FrameBorderSize => visuals.widgets.inactive.bg_stroke.width.into(),
+        StyleVar::ItemSpacing => spacing.item_spacing.into(),
+        StyleVar::ItemInnerSpacing => Vec2::splat(spacing.icon_spacing).into(),
+        StyleVar::IndentSpacing => spacing.indent.into(),
+        StyleVar::ScrollbarSize => spacing.scroll.bar_width.into(),
+        StyleVar::GrabMinSize => spacing.scroll.handle_min_length.into(),
+    }
+}
+
+/// Copy the fields a style variable drives from `from` to `to`.
+fn copy_var(from: &Style, to: &mut Style, var: StyleVar) {
+    let (from_spacing, spacing) = (&from.spacing, &mut to.spacing);
+    let (from_visuals, visuals) = (&from.visuals, &mut to.visuals);
+    let from_frames = [
+        &from_visuals.widgets.inactive,
+        &from_visuals.widgets.hovered,
+        &from_visuals.widgets.active,
+        &from_visuals.widgets.open,
+    ];
+    let widgets = &mut visuals.widgets;
+    let frames = [
+        &mut widgets.inactive,
+        &mut widgets.hovered,
+        &mut widgets.active,
+        &mut widgets.open,
+    ];
+    match var {
+        StyleVar::WindowPadding => spacing.window_margin = from_spacing.window_margin,
+        StyleVar::WindowRounding => visuals.window_rounding = from_visuals.window_rounding,
+        StyleVar::WindowBorderSize => {
+            visuals.window_stroke.width = from_visuals.window_stroke.width
+        }
+        StyleVar::PopupRounding => visuals.menu_rounding = from_visuals.menu_rounding,
+        StyleVar::FramePadding => spacing.button_padding = from_spacing.button_padding,
+        StyleVar::FrameRounding => {
+            for (widget, from) in frames.into_iter().zip(from_frames) {
+                widget.rounding = from.rounding;
+            }
+        }
+        StyleVar::FrameBorderSize => {
+            for (widget, from) in frames.into_iter().zip(from_frames) {
+                widget.bg_stroke.width = from.bg_stroke.width;
+            }
+        }
+        StyleVar::ItemSpacing => spacing.item_spacing = from_spacing.item_spacing,
+        StyleVar::ItemInnerSpacing => spacing.icon_spacing = from_spacing.icon_spacing,
+        StyleVar::IndentSpacing => spacing.indent = from_spacing.indent,
+        StyleVar::ScrollbarSize => spacing.scroll.bar_width = from_spacing.scroll.bar_width,
+        StyleVar::GrabMinSize => {
+            spacing.scroll.handle_min_length = from_spacing.scroll.handle_min_length
+        }
+    }
+}
+
+/// Undoes a change to a style.
+type Restore = Box<dyn FnOnce(&mut Style)>;
+
+/// Undoes a change to the style of a `Ui` when dropped. Derefs to the `Ui`.
+pub struct StyleGuard<'a> {
+    ui: &'a mut Ui,
+    restore: Option<Restore>,
+}
+
+impl<'a> StyleGuard<'a> {
+    /// Change the style of `ui`. `change` returns the function that undoes the change,
+    /// which is called on the style as it is when the guard is dropped.
+    pub(crate) fn new<R: FnOnce(&mut Style) + 'static>(
+        ui: &'a mut Ui,
+        change: impl FnOnce(&mut Style) -> R,
+    ) -> Self {
+        let restore = change(ui.style_mut());
+        Self {
+            ui,
+            restore: Some(Box::new(restore)),
+        }
+    }
+}
+
+impl Deref for StyleGuard<'_> {
+    type Target = Ui;
+
+    fn deref(&self) -> &Ui {
+        self.ui
+    }
+}
+
+impl DerefMut for StyleGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Ui {
+        self.ui
+    }
+}
+
+impl Drop for StyleGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(restore) = self.restore.take() {
+            restore(self.ui.style_mut());
+        }
+    }
+}
+
+/// Push and pop colors and style variables on a `Ui`.
+pub trait StyleStackExt {
+    /// Change the color of a slot until the guard is dropped.
+    fn push_style_color(&mut self, col: ImGuiCol, color: Color32) -> StyleGuard<'_>;
+
+    /// Change a style variable until the guard is dropped.
+    fn push_style_var(&mut self, var: StyleVar, value: impl Into<StyleValue>) -> StyleGuard<'_>;
+
+    /// Change the color of a slot for the widgets added by `add_contents`.
+    fn with_style_color<R>(
+        &mut self,
+        col: ImGuiCol,
+        color: Color32,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> R;
+
+    /// Change a style variable for the widgets added by `add_contents`.
+    fn with_style_var<R>(
+        &mut self,
+        var: StyleVar,
+        value: impl Into<StyleValue>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> R;
+}
+
+impl StyleStackExt for Ui {
+    fn push_style_color(&mut self, col: ImGuiCol, color: Color32) -> StyleGuard<'_> {
+        StyleGuard::new(self, |style| {
+            let mut saved = Vec::new();
+            imgui_col::for_each_field(style, col, &mut |field| {
+                saved.push(*field);
+                *field = color;
+            });
+            move |style: &mut Style| {
+                let mut saved = saved.into_iter();
+                imgui_col::for_each_field(style, col, &mut |field| {
+                    *field = saved.next().unwrap_or(*field);
+                });
+            }
+        })
+    }
+
+    fn push_style_var(&mut self, var: StyleVar, value: impl Into<StyleValue>) -> StyleGuard<'_> {
+        let value = value.into();
+        StyleGuard::new(self, |style| {
+            let saved = style.clone();
+            set_var(style, var, value);
+            move |style: &mut Style| copy_var(&saved, style, var)
+        })
+    }
+
+    fn with_style_color<R>(
+        &mut self,
+        col: ImGuiCol,
+        color: Color32,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> R {
+        add_contents(&mut self.push_style_color(col, color))
+    }
+
+    fn with_style_var<R>(
+        &mut self,
+        var: StyleVar,
+        value: impl Into<StyleValue>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> R {
+        add_contents(&mut self.push_style_var(var, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{CentralPanel, Color32, Context};
+
+    use super::*;
+
+    const VARS: [StyleVar; 12] = [
+        StyleVar::WindowPadding,
+        StyleVar::WindowRounding,
+        StyleVar::WindowBorderSize,
+        StyleVar::PopupRounding,
+        StyleVar::FramePadding,
+        StyleVar::FrameRounding,
+        StyleVar::FrameBorderSize,
+        StyleVar::ItemSpacing,
+        StyleVar::ItemInnerSpacing,
+        StyleVar::IndentSpacing,
+        StyleVar::ScrollbarSize,
+        StyleVar::GrabMinSize,
+    ];
+
+    /// Run `f` with the `Ui` of a central panel.
+    fn with_ui(f: impl FnOnce(&mut Ui)) {
+        let ctx = Context::default();
+        let mut f = Some(f);
+        let _ = ctx.run(Default::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| f.take().unwrap()(ui));
+        });
+    }
+
+    #[test]
+    fn vars_are_read_back() {
+        for var in VARS {
+            let mut style = crate::imgui::get_style();
+            let value = match get_var(&style, var) {
+                StyleValue::Float(_) => StyleValue::Float(7.0),
+                StyleValue::Vec2(_) if var == StyleVar::ItemInnerSpacing => {
+                    StyleValue::Vec2(Vec2::splat(7.0))
+                }
+                StyleValue::Vec2(_) => StyleValue::Vec2(Vec2::new(7.0, 3.0)),
+            };
+            set_var(&mut style, var, value);
+            assert_eq!(get_var(&style, var), value, "{var:?}");
+        }
+    }
+
+    #[test]
+    fn a_pop_only_restores_the_pushed_var() {
+        for var in VARS {
+            with_ui(|ui| {
+                let before = (**ui.style()).clone();
+                let mut guard = ui.push_style_var(var, 9.0);
+                assert_eq!(get_var(guard.style(), var).float(), 9.0, "{var:?}");
+                guard.style_mut().visuals.window_fill = Color32::RED;
+                drop(guard);
+                let mut expected = before;
+                expected.visuals.window_fill = Color32::RED;
+                assert!(**ui.style() == expected, "{var:?}");
+            });
+        }
+    }
+
+    #[test]
+    fn a_pop_only_restores_the_pushed_color() {
+        for col in crate::ImGuiCol::ALL {
+            with_ui(|ui| {
+                let before = (**ui.style()).clone();
+                let mut guard = ui.push_style_color(col, Color32::RED);
+                if col.has_field() {
+                    assert_eq!(imgui_col::get(guard.style(), col), Color32::RED, "{col:?}");
+                }
+                guard.style_mut().spacing.item_spacing = Vec2::splat(1.0);
+                drop(guard);
+                let mut expected = before;
+                expected.spacing.item_spacing = Vec2::splat(1.0);
+                assert!(**ui.style() == expected, "{col:?}");
+            });
+        }
+    }
+
+    #[test]
+    fn nested_guards_pop_in_order() {
+        with_ui(|ui| {
+            let before = (**ui.style()).clone();
+            let mut outer = ui.push_style_var(StyleVar::FramePadding, Vec2::new(8.0, 4.0));
+            let inner = outer.push_style_var(StyleVar::FramePadding, Vec2::new(2.0, 1.0));
+            assert_eq!(inner.spacing().button_padding, Vec2::new(2.0, 1.0));
+            drop(inner);
+            assert_eq!(outer.spacing().button_padding, Vec2::new(8.0, 4.0));
+            let inner = outer.push_style_var(StyleVar::ItemSpacing, 1.0);
+            assert_eq!(inner.spacing().item_spacing, Vec2::splat(1.0));
+            drop(inner);
+            drop(outer);
+            assert!(**ui.style() == before);
+        });
+    }
+}
@@ -0,0 +1,167 @@
+//! A plain text file format for themes.
+//!
+//! A theme file names the theme and font it is based on and lists the fields
+//! that differ from it, one `path = value` per line. Lines starting with `#` are
+//! comments. See [`crate::fields`] for the paths and values of the fields.
+//! ```text
+//! # Forest with square buttons
+//! base = Forest
+//! font = OpenSans
+//! visuals.widgets.inactive.rounding.nw = 0
+//! visuals.selection.bg_fill = #2f6b55
+//! ```
+
+use std::{fmt::Display, str::FromStr};
+
+use egui::Style;
+
+use crate::{diff::StyleDiff, fields, Font, FontSetup, Theme};
+
+/// A theme read from or written to a theme file.
+#[derive(Clone)]
+pub struct ThemeFile {
+    /// The theme the file is based on. Default: [`Theme::Imgui`].
+    pub base: Theme,
+    /// The font the file is based on. Default: the font of the base theme.
+    pub font: Font,
+    /// The complete style described by the file.
+    pub style: Style,
+}
+
+impl ThemeFile {
+    /// Describe `style` as changes to `base` with `font`.
+    pub fn from_style(base: Theme, font: Font, style: Style) -> Self {
+        Self { base, font, style }
+    }
+
+    /// The style of the base theme and font, without the changes of the file.
+    pub fn base_style(&self) -> Style {
+        self.base.get_style_with_font(&self.font.into())
+    }
+
+    /// Set the fonts and the style of this file.
+    pub fn apply(&self, ctx: &egui::Context) {
+        ctx.set_fonts(FontSetup::new(self.font).font_definitions());
+        ctx.set_style(self.style.clone());
+    }
+}
+
+/// The error returned when parsing a theme file fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line of the error, starting at 1.
+    pub line: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Writes the base theme, the font and every field that differs from them.
+impl Display for ThemeFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "base = {}", self.base)?;
+        writeln!(f, "font = {}", self.font)?;
+        let diff = StyleDiff::new(&self.base_style(), &self.style);
+        for change in diff.changes {
+            if let Some(value) = change.new {
+                writeln!(f, "{} = {value}", change.path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses a theme file. `base` and `font` may appear anywhere in the file.
+impl FromStr for ThemeFile {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut base = Theme::Imgui;
+        let mut font = None;
+        let mut fields = Vec::new();
+        for (index, line) in s.lines().enumerate() {
+            let line_number = index + 1;
+            let error = |message: String| ParseError {
+                line: line_number,
+                message,
+            };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error(format!("Expected `path = value`, found `{line}`")))?;
+            let (key, value) = (key.trim(), value.trim());
+            match key {
+                "base" => base = value.parse().map_err(|err| error(format!("{err}")))?,
+                "font" => font = Some(value.parse().map_err(|err| error(format!("{err}")))?),
+                _ => fields.push((line_number, key, value)),
+            }
+        }
+
+        let font = font.or(base.font()).unwrap_or_default();
+        let mut style = base.get_style_with_font(&font.into());
+        for (line, path, value) in fields {
+            fields::set_field(&mut style, path, value)
+                .map_err(|message| ParseError { line, message })?;
+        }
+        Ok(Self { base, font, style })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::Color32;
+
+    use super::*;
+
+    fn round_trip(file: &ThemeFile) -> ThemeFile {
+        file.to_string().parse().unwrap()
+    }
+
+    #[test]
+    fn theme_files_round_trip() {
+        for theme in Theme::ALL {
+            let mut style = theme.get_style();
+            style.visuals.widgets.inactive.rounding.nw = 7.5;
+            style.visuals.selection.bg_fill = Color32::from_rgba_unmultiplied(47, 107, 85, 200);
+            style.visuals.faint_bg_color = Color32::from_rgba_premultiplied(9, 9, 9, 0);
+            let file = ThemeFile::from_style(theme, Font::OpenSans, style);
+            let parsed = round_trip(&file);
+            assert_eq!(parsed.base, theme);
+            assert_eq!(parsed.font, Font::OpenSans);
+            assert!(StyleDiff::new(&file.style, &parsed.style).is_empty());
+        }
+    }
+
+    #[test]
+    fn styles_of_other_themes_round_trip() {
+        for theme in Theme::ALL {
+            let file = ThemeFile::from_style(Theme::Imgui, Font::ProggyClean, theme.get_style());
+            let diff = StyleDiff::new(&file.style, &round_trip(&file).style);
+            assert!(diff.is_empty(), "{}: {diff}", theme.name());
+        }
+    }
+
+    #[test]
+    fn malformed_theme_files_are_rejected() {
+        let errors = [
+            ("base = Nope", 1),
+            ("font = OpenSans\nvisuals.nope = 1", 2),
+            ("visuals.window_fill = #zzzzzz", 1),
+            ("# comment\n\nspacing.indent", 3),
+            ("spacing.indent = wide", 1),
+        ];
+        for (text, line) in errors {
+            let error = text.parse::<ThemeFile>().err().unwrap();
+            assert_eq!(error.line, line, "{text}");
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! Named text styles beyond the five text styles egui defines.
+//!
+//! The sizes of all text styles are generated from a [`TypeRamp`] and registered
+//! as [`TextStyle::Name`] entries by [`FontSetup::get_style`](crate::FontSetup::get_style).
+
+use std::collections::BTreeMap;
+
+use egui::{FontFamily, FontId, Response, RichText, TextStyle, Ui};
+
+pub const TITLE: &str = "Title";
+pub const SUBHEADING: &str = "Subheading";
+pub const LABEL: &str = "Label";
+pub const CAPTION: &str = "Caption";
+pub const CODE_SMALL: &str = "CodeSmall";
+
+/// Get the named text style with the given name.
+pub fn text_style(name: &str) -> TextStyle {
+    TextStyle::Name(name.into())
+}
+
+/// A typographic scale where each step is `ratio` times larger than the previous one.
+#[derive(Clone, Copy, PartialEq)]
+pub struct TypeRamp {
+    /// The size of the body text.
+    pub base: f32,
+    /// The size ratio between two steps of the ramp.
+    pub ratio: f32,
+}
+
+impl TypeRamp {
+    pub fn new(base: f32, ratio: f32) -> Self {
+        Self { base, ratio }
+    }
+
+    /// The size of the given step, rounded to whole points.
+    /// Step zero is the body text size.
+    pub fn size(&self, step: i32) -> f32 {
+        (self.base * self.ratio.powi(step)).round()
+    }
+
+    /// Get the text styles of this ramp.
+    /// This includes the five text styles of egui and all named text styles.
+    pub fn text_styles(&self) -> BTreeMap<TextStyle, FontId> {
+        let mut text_styles: BTreeMap<TextStyle, FontId> = [
+            (TextStyle::Small, self.font_id(-1, FontFamily::Proportional)),
+            (TextStyle::Body, self.font_id(0, FontFamily::Proportional)),
+            (TextStyle::Monospace, self.font_id(0, FontFamily::Monospace)),
+            (TextStyle::Button, self.font_id(0, FontFamily::Proportional)),
+            (
+                TextStyle::Heading,
+                self.font_id(2, FontFamily::Proportional),
+            ),
+        ]
+        .into();
+        text_styles.extend(self.named_text_styles());
+        text_styles
+    }
+
+    /// Get only the named text styles of this ramp.
+    pub fn named_text_styles(&self) -> BTreeMap<TextStyle, FontId> {
+        [
+            (text_style(TITLE), self.font_id(3, FontFamily::Proportional)),
+            (
+                text_style(SUBHEADING),
+                self.font_id(1, FontFamily::Proportional),
+            ),
+            (text_style(LABEL), self.font_id(0, FontFamily::Proportional)),
+            (
+                text_style(CAPTION),
+                self.font_id(-1, FontFamily::Proportional),
+            ),
+            (
+                text_style(CODE_SMALL),
+                self.font_id(-1, FontFamily::Monospace),
+            ),
+        ]
+        .into()
+    }
+
+    fn font_id(&self, step: i32, family: FontFamily) -> FontId {
+        FontId::new(self.size(step), family)
+    }
+}
+
+impl Default for TypeRamp {
+    fn default() -> Self {
+        Self::new(12.0, 1.25)
+    }
+}
+
+/// Helpers to show text in the named text styles.
+///
+/// If a named text style is missing from the style of the `Ui`
+/// the closest of egui's text styles is used instead.
+pub trait TypographyExt {
+    /// Show text in the text style with the given name.
+    fn styled_label(
+        &mut self,
+        name: &str,
+        fallback: TextStyle,
+        text: impl Into<String>,
+    ) -> Response;
+
+    fn title(&mut self, text: impl Into<String>) -> Response {
+        self.styled_label(TITLE, TextStyle::Heading, text)
+    }
+
+    fn subheading(&mut self, text: impl Into<String>) -> Response {
+        self.styled_label(SUBHEADING, TextStyle::Heading, text)
+    }
+
+    fn field_label(&mut self, text: impl Into<String>) -> Response {
+        self.styled_label(LABEL, TextStyle::Body, text)
+    }
+
+    fn caption(&mut self, text: impl Into<String>) -> Response {
+        self.styled_label(CAPTION, TextStyle::Small, text)
+    }
+
+    fn code_small(&mut self, text: impl Into<String>) -> Response {
+        self.styled_label(CODE_SMALL, TextStyle::Monospace, text)
+    }
+}
+
+impl TypographyExt for Ui {
+    fn styled_label(
+        &mut self,
+        name: &str,
+        fallback: TextStyle,
+        text: impl Into<String>,
+    ) -> Response {
+        let mut style = text_style(name);
+        if !self.style().text_styles.contains_key(&style) {
+            style = fallback;
+        }
+        self.label(RichText::new(text).text_style(style))
+    }
+}
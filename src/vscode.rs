@@ -0,0 +1,408 @@
+//! Import VS Code color themes.
+//!
+//! The `colors` of a theme's JSON file are mapped onto the palette roles and a few
+//! fields of the style. `tokenColors` only color source code and are ignored, as is
+//! a theme that is pulled in with `include`.
+//!
+//! | Key | Role or field |
+//! | --- | --- |
+//! | `editor.background` | window background |
+//! | `sideBar.background` | `panel_fill` |
+//! | `foreground`, `editor.foreground` | text |
+//! | `button.foreground` | text of hovered and active widgets |
+//! | `editorWidget.background` | non-interactive widgets |
+//! | `input.background` | text edits |
+//! | `textCodeBlock.background` | code |
+//! | `contrastBorder`, `panel.border`, `editorGroup.border` | borders |
+//! | `checkbox.background`, `dropdown.background` | frames |
+//! | `list.hoverBackground` | hovered frames |
+//! | `list.activeSelectionBackground` | active frames and selection |
+//! | `button.background` | buttons |
+//! | `button.hoverBackground` | hovered buttons, and mixed toward the text for pressed ones |
+//! | `activityBarBadge.background` | accent of the mixed colors, if there's no button color |
+//! | `editor.selectionBackground` | selection, if there's no list selection |
+//! | `focusBorder` | `selection.stroke` |
+//! | `editorCursor.foreground` | `text_cursor` |
+//! | `textLink.foreground` | hyperlinks |
+//! | `editorWarning.foreground` | warnings |
+//! | `errorForeground`, `editorError.foreground` | errors |
+//!
+//! Roles without a key are mixed from the background, text and button colors.
+//! ```no_run
+//! # use dear_egui::VsCodeTheme;
+//! # fn f(ctx: &egui::Context) -> Result<(), Box<dyn std::error::Error>> {
+//! let theme: VsCodeTheme = std::fs::read_to_string("monokai-color-theme.json")?.parse()?;
+//! for key in theme.unmapped_keys() {
+//!     eprintln!("{key} is not used");
+//! }
+//! ctx.set_style(theme.to_style());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::str::FromStr;
+
+use egui::{Color32, Rgba, Style};
+
+use crate::{
+    fields::parse_color,
+    jsonc::{self, Value},
+    registry::ThemeProvider,
+    schemes::{mix, Scheme},
+    theme_file::ParseError,
+    Palette,
+};
+
+/// The keys of the colors the palette is mixed from, each in the order of preference.
+const BACKGROUND: &[&str] = &["editor.background"];
+const FOREGROUND: &[&str] = &["foreground", "editor.foreground"];
+const ACCENT: &[&str] = &[
+    "button.background",
+    "focusBorder",
+    "activityBarBadge.background",
+];
+
+/// Where the color of a key goes.
+#[derive(Clone, Copy)]
+enum Target {
+    /// Only mixed into the palette.
+    Mix,
+    /// A palette role.
+    Role(fn(&mut Palette) -> &mut Color32),
+    /// A field of the style that is not covered by the palette.
+    Field(fn(&mut Style) -> &mut Color32),
+}
+
+/// Every key this importer reads. If a target has more than one key, the first key
+/// the theme has a color for is used.
+const KEYS: [(&[&str], Target); 20] = [
+    (BACKGROUND, Target::Mix),
+    (FOREGROUND, Target::Mix),
+    (ACCENT, Target::Mix),
+    (
+        &["sideBar.background"],
+        Target::Field(|style| &mut style.visuals.panel_fill),
+    ),
+    (
+        &["editorWidget.background"],
+        Target::Role(|palette| &mut palette.widget_bg),
+    ),
+    (
+        &["input.background"],
+        Target::Role(|palette| &mut palette.extreme_bg),
+    ),
+    (
+        &["textCodeBlock.background"],
+        Target::Role(|palette| &mut palette.code_bg),
+    ),
+    (
+        &["contrastBorder", "panel.border", "editorGroup.border"],
+        Target::Role(|palette| &mut palette.border),
+    ),
+    (
+        &["button.foreground"],
+        Target::Role(|palette| &mut palette.text_active),
+    ),
+    (
+        &["checkbox.background", "dropdown.background"],
+        Target::Role(|palette| &mut palette.frame_bg),
+    ),
+    (
+        &["list.hoverBackground"],
+        Target::Role(|palette| &mut palette.frame_bg_hovered),
+    ),
+    (
+        &["list.activeSelectionBackground"],
+        Target::Role(|palette| &mut palette.frame_bg_active),
+    ),
+    (
+        &["button.background"],
+        Target::Role(|palette| &mut palette.button),
+    ),
+    (
+        &["button.hoverBackground"],
+        Target::Role(|palette| &mut palette.button_hovered),
+    ),
+    (
+        &[
+            "list.activeSelectionBackground",
+            "editor.selectionBackground",
+        ],
+        Target::Role(|palette| &mut palette.selection),
+    ),
+    (
+        &["focusBorder"],
+        Target::Field(|style| &mut style.visuals.selection.stroke.color),
+    ),
+    (
+        &["editorCursor.foreground"],
+        Target::Field(|style| &mut style.visuals.text_cursor.color),
+    ),
+    (
+        &["textLink.foreground"],
+        Target::Role(|palette| &mut palette.hyperlink),
+    ),
+    (
+        &["editorWarning.foreground"],
+        Target::Role(|palette| &mut palette.warn),
+    ),
+    (
+        &["errorForeground", "editorError.foreground"],
+        Target::Role(|palette| &mut palette.error),
+    ),
+];
+
+/// The colors of a VS Code theme.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VsCodeTheme {
+    pub name: String,
+    /// From the `type` of the theme, or the brightness of the background if it has none.
+    pub dark_mode: bool,
+    /// The `colors` in the order of the file.
+    pub colors: Vec<(String, Color32)>,
+    /// The keys of `colors` whose value is not a color. They are skipped.
+    pub invalid_keys: Vec<String>,
+}
+
+impl VsCodeTheme {
+    /// Get a color by its key.
+    pub fn color(&self, key: &str) -> Option<Color32> {
+        self.colors
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, color)| *color)
+    }
+
+    /// The first of `keys` the theme has a color for.
+    fn first(&self, keys: &[&str]) -> Option<Color32> {
+        keys.iter().find_map(|key| self.color(key))
+    }
+
+    /// The keys of the theme that are not used by [`VsCodeTheme::to_style`],
+    /// including the keys whose value is not a color.
+    pub fn unmapped_keys(&self) -> Vec<&str> {
+        let mapped = |key: &&str| KEYS.iter().any(|(keys, _)| keys.contains(key));
+        self.colors
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .filter(|key| !mapped(key))
+            .chain(self.invalid_keys.iter().map(String::as_str))
+            .collect()
+    }
+
+    /// Map the colors onto the palette roles.
+    pub fn to_palette(&self) -> Palette {
+        let (default_bg, default_text) = if self.dark_mode {
+            (Color32::from_gray(30), Color32::from_gray(204))
+        } else {
+            (Color32::WHITE, Color32::from_gray(51))
+        };
+        let bg = self.first(BACKGROUND).unwrap_or(default_bg);
+        let text = self.first(FOREGROUND).unwrap_or(default_text);
+        let accent = self.first(ACCENT).unwrap_or(Color32::from_rgb(0, 122, 204));
+        let surface = |t| mix(bg, text, t);
+        let mut palette = Scheme {
+            bg,
+            extreme_bg: if self.dark_mode {
+                mix(bg, Color32::BLACK, 0.3)
+            } else {
+                mix(bg, Color32::WHITE, 0.6)
+            },
+            code_bg: surface(0.08),
+            surfaces: [surface(0.08), surface(0.15), surface(0.22)],
+            border: surface(0.2),
+            text,
+            text_active: if self.dark_mode {
+                mix(text, Color32::WHITE, 0.5)
+            } else {
+                mix(text, Color32::BLACK, 0.5)
+            },
+            accent,
+            hyperlink: accent,
+            warn: Color32::from_rgb(204, 167, 0),
+            error: Color32::from_rgb(241, 76, 76),
+        }
+        .palette();
+        for (keys, target) in KEYS {
+            if let (Target::Role(role), Some(color)) = (target, self.first(keys)) {
+                *role(&mut palette) = color;
+            }
+        }
+        // VS Code has no color for pressed buttons.
+        if self.color("button.hoverBackground").is_some() {
+            palette.button_active = mix(palette.button_hovered, text, 0.15);
+        }
+        palette
+    }
+
+    /// Get a style in the colors of the theme with the spacing and shapes of the color themes.
+    pub fn to_style(&self) -> Style {
+        let mut style = self.to_palette().get_style();
+        style.visuals.dark_mode = self.dark_mode;
+        for (keys, target) in KEYS {
+            if let (Target::Field(field), Some(color)) = (target, self.first(keys)) {
+                *field(&mut style) = color;
+            }
+        }
+        style
+    }
+}
+
+impl ThemeProvider for VsCodeTheme {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_base_style(&self) -> Style {
+        self.to_style()
+    }
+
+    fn palette(&self) -> Option<Palette> {
+        Some(self.to_palette())
+    }
+}
+
+/// Parses the JSON of a theme, which may contain comments and trailing commas.
+/// Colors that can't be parsed are skipped and listed in `invalid_keys`.
+impl FromStr for VsCodeTheme {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = jsonc::parse(s)?;
+        let name = json
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_owned();
+        let mut colors = Vec::new();
+        let mut invalid_keys = Vec::new();
+        if let Some(Value::Object(members)) = json.get("colors") {
+            for (key, value, _) in members {
+                match value.as_str().map(parse_color) {
+                    Some(Ok(color)) => colors.push((key.clone(), color)),
+                    _ => invalid_keys.push(key.clone()),
+                }
+            }
+        }
+        let dark_mode = match json.get("type").and_then(Value::as_str) {
+            Some("light" | "hcLight") => false,
+            Some(_) => true,
+            None => !colors
+                .iter()
+                .any(|(key, bg)| key == "editor.background" && Rgba::from(*bg).intensity() >= 0.5),
+        };
+        Ok(Self {
+            name,
+            dark_mode,
+            colors,
+            invalid_keys,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THEME: &str = r##"{
+        // A comment
+        "name": "Sample",
+        "colors": {
+            "editor.background": "#1e1e1e",
+            "editor.foreground": "#d4d4d4",
+            "button.background": "#0e639c",
+            "editorCursor.foreground": "#aeafad",
+            "terminal.ansiRed": "#cd3131",
+            "foo": null,
+            "bar": "not a color",
+        },
+    }"##;
+
+    #[test]
+    fn colors_are_mapped() {
+        let theme: VsCodeTheme = THEME.parse().unwrap();
+        assert_eq!(theme.name, "Sample");
+        assert!(theme.dark_mode);
+        let palette = theme.to_palette();
+        assert_eq!(palette.button, Color32::from_rgb(0x0e, 0x63, 0x9c));
+        let style = theme.to_style();
+        assert_eq!(
+            style.visuals.text_cursor.color,
+            Color32::from_rgb(0xae, 0xaf, 0xad)
+        );
+    }
+
+    #[test]
+    fn pressed_buttons_differ_from_hovered_ones() {
+        let theme: VsCodeTheme = r##"{"colors": {"button.hoverBackground": "#1177bb"}}"##
+            .parse()
+            .unwrap();
+        let palette = theme.to_palette();
+        assert_eq!(palette.button_hovered, Color32::from_rgb(0x11, 0x77, 0xbb));
+        assert_ne!(palette.button_active, palette.button_hovered);
+    }
+
+    #[test]
+    fn invalid_colors_are_skipped() {
+        let theme: VsCodeTheme = THEME.parse().unwrap();
+        assert_eq!(theme.colors.len(), 5);
+        assert_eq!(theme.invalid_keys, ["foo", "bar"]);
+        assert_eq!(theme.unmapped_keys(), ["terminal.ansiRed", "foo", "bar"]);
+    }
+
+    #[test]
+    fn light_themes_are_detected() {
+        let theme: VsCodeTheme = r##"{"colors": {"editor.background": "#ffffff"}}"##
+            .parse()
+            .unwrap();
+        assert!(!theme.dark_mode);
+        let theme: VsCodeTheme = r#"{"type": "hcLight"}"#.parse().unwrap();
+        assert!(!theme.dark_mode);
+    }
+
+    /// The keys in the table of the module documentation.
+    const DOCUMENTED: [&str; 25] = [
+        "editor.background",
+        "sideBar.background",
+        "foreground",
+        "editor.foreground",
+        "button.foreground",
+        "editorWidget.background",
+        "input.background",
+        "textCodeBlock.background",
+        "contrastBorder",
+        "panel.border",
+        "editorGroup.border",
+        "checkbox.background",
+        "dropdown.background",
+        "list.hoverBackground",
+        "list.activeSelectionBackground",
+        "button.background",
+        "button.hoverBackground",
+        "activityBarBadge.background",
+        "editor.selectionBackground",
+        "focusBorder",
+        "editorCursor.foreground",
+        "textLink.foreground",
+        "editorWarning.foreground",
+        "errorForeground",
+        "editorError.foreground",
+    ];
+
+    #[test]
+    fn the_table_lists_every_key() {
+        let mut documented = DOCUMENTED.to_vec();
+        let mut mapped: Vec<&str> = KEYS.iter().flat_map(|(keys, _)| *keys).copied().collect();
+        documented.sort_unstable();
+        documented.dedup();
+        mapped.sort_unstable();
+        mapped.dedup();
+        assert_eq!(documented, mapped);
+    }
+
+    #[test]
+    fn malformed_input_is_an_error() {
+        assert!("{".parse::<VsCodeTheme>().is_err());
+        assert!("[".repeat(1000).parse::<VsCodeTheme>().is_err());
+    }
+}
@@ -0,0 +1,96 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use egui::{Align2, Area, Context, Frame, Id, Order, RichText};
+
+use crate::ThemeFile;
+
+/// Applies a theme file and applies it again whenever the file changes.
+///
+/// The modification time of the file is polled every frame. Errors while reading
+/// or parsing the file are shown in an overlay and the last good theme stays applied.
+/// ```no_run
+/// # use dear_egui::ThemeWatcher;
+/// # fn f(ctx: &egui::Context) {
+/// let mut watcher = ThemeWatcher::new("theme.txt");
+/// // In the update loop:
+/// watcher.update(ctx);
+/// # }
+/// ```
+pub struct ThemeWatcher {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    error: Option<String>,
+    poll_interval: Duration,
+}
+
+impl ThemeWatcher {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            modified: None,
+            error: None,
+            poll_interval: Duration::from_millis(500),
+        }
+    }
+
+    /// How often to check the file while the app is otherwise idle. Default: 500 ms.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// The watched file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The error of the last attempt to load the file.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Apply the file if it changed since the last call and show the last error.
+    pub fn update(&mut self, ctx: &Context) {
+        ctx.request_repaint_after(self.poll_interval);
+        match std::fs::metadata(&self.path).and_then(|metadata| metadata.modified()) {
+            Ok(modified) if Some(modified) != self.modified => {
+                self.modified = Some(modified);
+                self.error = self.load(ctx).err();
+            }
+            Ok(_) => (),
+            Err(err) => {
+                // Load the file again once it is back.
+                self.modified = None;
+                self.error = Some(format!("{}: {err}", self.path.display()));
+            }
+        }
+        if let Some(error) = &self.error {
+            show_error(ctx, error);
+        }
+    }
+
+    fn load(&self, ctx: &Context) -> Result<(), String> {
+        let text = std::fs::read_to_string(&self.path)
+            .map_err(|err| format!("{}: {err}", self.path.display()))?;
+        let file: ThemeFile = text
+            .parse()
+            .map_err(|err| format!("{}: {err}", self.path.display()))?;
+        file.apply(ctx);
+        Ok(())
+    }
+}
+
+fn show_error(ctx: &Context, error: &str) {
+    Area::new(Id::new("dear_egui_theme_watcher_error"))
+        .order(Order::Foreground)
+        .anchor(Align2::LEFT_BOTTOM, [8.0, -8.0])
+        .interactable(false)
+        .show(ctx, |ui| {
+            Frame::popup(ui.style()).show(ui, |ui| {
+                ui.label(RichText::new(error).color(ui.visuals().error_fg_color));
+            });
+        });
+}
@@ -0,0 +1,40 @@
+//! The output of `codegen::style_to_rust` is checked in as `codegen/style.rs`
+//! and compiled here, so a change to the generator that produces invalid Rust
+//! fails to build.
+
+use dear_egui::{codegen::style_to_rust, imgui};
+use egui::{FontFamily, FontId, Style, TextStyle};
+
+mod generated {
+    include!("codegen/style.rs");
+}
+
+/// The ImGui style with the values that need more than a plain literal.
+fn sample_style() -> Style {
+    let mut style = imgui::get_style();
+    style.text_styles.insert(
+        TextStyle::Name("Title".into()),
+        FontId::new(24.0, FontFamily::Name("Brand".into())),
+    );
+    style.override_font_id = Some(FontId::monospace(11.0));
+    style.spacing.tooltip_width = f32::INFINITY;
+    style.spacing.combo_height = f32::NEG_INFINITY;
+    style
+}
+
+#[test]
+fn generated_code_is_up_to_date() {
+    let source = style_to_rust(&sample_style());
+    if std::env::var_os("DEAR_EGUI_BLESS").is_some() {
+        std::fs::write("tests/codegen/style.rs", &source).unwrap();
+    }
+    assert!(
+        source == include_str!("codegen/style.rs"),
+        "The generated code changed. Run the test with `DEAR_EGUI_BLESS=1` to update it."
+    );
+}
+
+#[test]
+fn generated_code_builds_the_style() {
+    assert!(generated::get_style() == sample_style());
+}
@@ -0,0 +1,200 @@
+use egui::{
+    epaint::Shadow,
+    style::{Interaction, ScrollStyle, Selection, Spacing, WidgetVisuals, Widgets},
+    Color32, FontFamily, FontId, Margin, Rounding, Stroke, Style, TextStyle, Vec2, Visuals,
+};
+
+pub fn get_style() -> Style {
+    Style {
+        override_text_style: None,
+        override_font_id: Some(FontId::new(11.0, FontFamily::Monospace)),
+        text_styles: [
+            (TextStyle::Small, FontId::new(9.0, FontFamily::Proportional)),
+            (TextStyle::Body, FontId::new(12.5, FontFamily::Proportional)),
+            (TextStyle::Monospace, FontId::new(12.0, FontFamily::Monospace)),
+            (TextStyle::Button, FontId::new(12.5, FontFamily::Proportional)),
+            (TextStyle::Heading, FontId::new(18.0, FontFamily::Proportional)),
+            (TextStyle::Name("Title".into()), FontId::new(24.0, FontFamily::Name("Brand".into()))),
+        ].into(),
+        drag_value_text_style: TextStyle::Button,
+        wrap: None,
+        spacing: Spacing {
+            item_spacing: Vec2 { x: 3.0, y: 3.0 },
+            window_margin: Margin {
+                left: 6.0,
+                right: 6.0,
+                top: 6.0,
+                bottom: 6.0,
+            },
+            button_padding: Vec2 { x: 4.0, y: 1.0 },
+            menu_margin: Margin {
+                left: 6.0,
+                right: 6.0,
+                top: 6.0,
+                bottom: 6.0,
+            },
+            indent: 18.0,
+            interact_size: Vec2 { x: 40.0, y: 18.0 },
+            slider_width: 100.0,
+            combo_width: 100.0,
+            text_edit_width: 280.0,
+            icon_width: 18.0,
+            icon_width_inner: 10.0,
+            icon_spacing: 4.0,
+            tooltip_width: f32::INFINITY,
+            indent_ends_with_horizontal_line: false,
+            combo_height: f32::NEG_INFINITY,
+            scroll: ScrollStyle {
+                floating: false,
+                bar_width: 8.0,
+                handle_min_length: 12.0,
+                bar_inner_margin: 4.0,
+                bar_outer_margin: 0.0,
+                floating_width: 2.0,
+                floating_allocated_width: 12.0,
+                foreground_color: false,
+                dormant_background_opacity: 0.0,
+                active_background_opacity: 0.4,
+                interact_background_opacity: 0.7,
+                dormant_handle_opacity: 0.0,
+                active_handle_opacity: 0.6,
+                interact_handle_opacity: 1.0,
+            },
+            menu_width: 150.0,
+        },
+        interaction: Interaction {
+            resize_grab_radius_side: 5.0,
+            resize_grab_radius_corner: 10.0,
+            show_tooltips_only_when_still: true,
+            tooltip_delay: 0.7,
+            selectable_labels: true,
+            multi_widget_text_select: true,
+        },
+        visuals: Visuals {
+            dark_mode: true,
+            override_text_color: None,
+            widgets: Widgets {
+                noninteractive: WidgetVisuals {
+                    bg_fill: Color32::from_rgba_premultiplied(27, 27, 27, 255),
+                    weak_bg_fill: Color32::from_rgba_premultiplied(27, 27, 27, 255),
+                    bg_stroke: Stroke {
+                        width: 1.0,
+                        color: Color32::from_rgba_premultiplied(63, 63, 72, 255),
+                    },
+                    rounding: Rounding { nw: 0.0, ne: 0.0, sw: 0.0, se: 0.0 },
+                    fg_stroke: Stroke {
+                        width: 1.0,
+                        color: Color32::from_rgba_premultiplied(240, 240, 240, 255),
+                    },
+                    expansion: 0.0,
+                },
+                inactive: WidgetVisuals {
+                    bg_fill: Color32::from_rgba_premultiplied(29, 47, 73, 255),
+                    weak_bg_fill: Color32::from_rgba_premultiplied(29, 47, 73, 255),
+                    bg_stroke: Stroke {
+                        width: 0.0,
+                        color: Color32::from_rgba_premultiplied(0, 0, 0, 0),
+                    },
+                    rounding: Rounding { nw: 0.0, ne: 0.0, sw: 0.0, se: 0.0 },
+                    fg_stroke: Stroke {
+                        width: 1.0,
+                        color: Color32::from_rgba_premultiplied(240, 240, 240, 255),
+                    },
+                    expansion: 0.0,
+                },
+                hovered: WidgetVisuals {
+                    bg_fill: Color32::from_rgba_premultiplied(49, 106, 173, 255),
+                    weak_bg_fill: Color32::from_rgba_premultiplied(49, 106, 173, 255),
+                    bg_stroke: Stroke {
+                        width: 0.0,
+                        color: Color32::from_rgba_premultiplied(0, 0, 0, 0),
+                    },
+                    rounding: Rounding { nw: 0.0, ne: 0.0, sw: 0.0, se: 0.0 },
+                    fg_stroke: Stroke {
+                        width: 1.0,
+                        color: Color32::from_rgba_premultiplied(255, 255, 255, 255),
+                    },
+                    expansion: 0.0,
+                },
+                active: WidgetVisuals {
+                    bg_fill: Color32::from_rgba_premultiplied(35, 69, 109, 255),
+                    weak_bg_fill: Color32::from_rgba_premultiplied(35, 69, 109, 255),
+                    bg_stroke: Stroke {
+                        width: 0.0,
+                        color: Color32::from_rgba_premultiplied(0, 0, 0, 0),
+                    },
+                    rounding: Rounding { nw: 0.0, ne: 0.0, sw: 0.0, se: 0.0 },
+                    fg_stroke: Stroke {
+                        width: 1.0,
+                        color: Color32::from_rgba_premultiplied(255, 255, 255, 255),
+                    },
+                    expansion: 0.0,
+                },
+                open: WidgetVisuals {
+                    bg_fill: Color32::from_rgba_premultiplied(29, 47, 73, 255),
+                    weak_bg_fill: Color32::from_rgba_premultiplied(29, 47, 73, 255),
+                    bg_stroke: Stroke {
+                        width: 0.0,
+                        color: Color32::from_rgba_premultiplied(0, 0, 0, 0),
+                    },
+                    rounding: Rounding { nw: 0.0, ne: 0.0, sw: 0.0, se: 0.0 },
+                    fg_stroke: Stroke {
+                        width: 1.0,
+                        color: Color32::from_rgba_premultiplied(240, 240, 240, 255),
+                    },
+                    expansion: 0.0,
+                },
+            },
+            selection: Selection {
+                bg_fill: Color32::from_rgba_premultiplied(0, 92, 128, 255),
+                stroke: Stroke {
+                    width: 1.0,
+                    color: Color32::from_rgba_premultiplied(192, 222, 255, 255),
+                },
+            },
+            hyperlink_color: Color32::from_rgba_premultiplied(90, 170, 255, 255),
+            faint_bg_color: Color32::from_rgba_premultiplied(5, 5, 5, 0),
+            extreme_bg_color: Color32::from_rgba_premultiplied(10, 10, 10, 255),
+            code_bg_color: Color32::from_rgba_premultiplied(10, 10, 10, 255),
+            warn_fg_color: Color32::from_rgba_premultiplied(255, 143, 0, 255),
+            error_fg_color: Color32::from_rgba_premultiplied(255, 0, 0, 255),
+            window_rounding: Rounding { nw: 0.0, ne: 0.0, sw: 0.0, se: 0.0 },
+            window_shadow: Shadow {
+                extrusion: 32.0,
+                color: Color32::from_rgba_premultiplied(0, 0, 0, 96),
+            },
+            window_fill: Color32::from_rgba_premultiplied(15, 15, 15, 255),
+            window_stroke: Stroke {
+                width: 1.0,
+                color: Color32::from_rgba_premultiplied(61, 61, 73, 255),
+            },
+            menu_rounding: Rounding { nw: 0.0, ne: 0.0, sw: 0.0, se: 0.0 },
+            panel_fill: Color32::from_rgba_premultiplied(15, 15, 15, 255),
+            popup_shadow: Shadow {
+                extrusion: 0.0,
+                color: Color32::from_rgba_premultiplied(0, 0, 0, 0),
+            },
+            resize_corner_size: 12.0,
+            text_cursor_preview: true,
+            clip_rect_margin: 3.0,
+            button_frame: true,
+            collapsing_header_frame: true,
+            indent_has_left_vline: true,
+            striped: false,
+            slider_trailing_fill: false,
+            text_cursor: Stroke {
+                width: 2.0,
+                color: Color32::from_rgba_premultiplied(192, 222, 255, 255),
+            },
+            interact_cursor: None,
+            image_loading_spinners: true,
+            handle_shape: egui::style::HandleShape::Rect { aspect_ratio: 0.5 },
+            window_highlight_topmost: false,
+            numeric_color_space: egui::style::NumericColorSpace::GammaByte,
+        },
+        animation_time: 0.083333336,
+        explanation_tooltips: false,
+        debug: Default::default(),
+        always_scroll_the_only_direction: false,
+    }
+}